@@ -0,0 +1,476 @@
+//! Explains conflicts by searching the item graph for example inputs.
+//!
+//! The search walks the LR(1) items of the [`Graph`], either by shifting a symbol (which moves to
+//! another state) or by stepping into a production of the non-terminal after the dot (which stays
+//! in the same state). The path that reaches a conflicting item is a derivation of the input seen
+//! so far. The first action is searched with exact lookaheads, the second one is then constrained
+//! to the same sequence of states, so both derivations share the prefix up to the conflict.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, VecDeque},
+    fmt::Display,
+};
+
+use itertools::Itertools;
+
+use crate::{
+    generator::{Graph, Uid},
+    grammar::{Grammar, Token},
+    string_pool::{Id, Pool},
+    table::{Action, Conflict},
+};
+
+/// give up after visiting this many search nodes
+const SEARCH_LIMIT: usize = 200_000;
+
+/// an LR(0) item: `rule -> before . after`
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Item {
+    rule: Id,
+    before: Vec<Token>,
+    after: Vec<Token>,
+}
+
+impl Item {
+    fn advance(&self) -> Item {
+        let mut item = self.clone();
+        let next = item.after.remove(0);
+        item.before.push(next);
+        item
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Node {
+    /// the current state, or the index into the state path if the search is constrained
+    at: Uid,
+    item: Item,
+    /// the terminal that has to follow the rule of `item`, if lookaheads are tracked
+    follow: Option<Token>,
+}
+
+#[derive(Debug, Clone)]
+enum Step {
+    Shift(Uid),
+    Produce,
+}
+
+/// A derivation of the input up to a conflicting item. Every item is derived from the symbol
+/// after the dot of the item before it.
+#[derive(Debug, Clone)]
+pub struct Derivation {
+    items: Vec<Item>,
+    states: Vec<Uid>,
+}
+
+impl Derivation {
+    fn from_path(start: Item, start_state: Uid, steps: Vec<(Step, Item)>) -> Self {
+        let mut items = vec![start];
+        let mut states = vec![start_state];
+        for (step, item) in steps {
+            match step {
+                Step::Shift(state) => {
+                    states.push(state);
+                    *items.last_mut().expect("derivation is never empty") = item;
+                }
+                Step::Produce => items.push(item),
+            }
+        }
+
+        Derivation { items, states }
+    }
+
+    /// the sentential form this derivation produces, with the position of the conflict
+    fn sentence(&self) -> (Vec<Token>, Vec<Token>) {
+        let mut before = Vec::new();
+        let mut after = Vec::new();
+        for (i, item) in self.items.iter().enumerate() {
+            before.extend(item.before.iter().copied());
+            let rest = if i + 1 == self.items.len() {
+                &item.after[..]
+            } else {
+                &item.after[1..]
+            };
+            after.splice(0..0, rest.iter().copied().filter(|&x| x != Token::Eof));
+        }
+        (before, after)
+    }
+}
+
+pub struct Counterexample {
+    conflict: Conflict,
+    derivations: Option<(Derivation, Derivation)>,
+}
+
+struct Search<'a> {
+    grammar: &'a Grammar,
+    edges: BTreeMap<Uid, BTreeMap<Token, Uid>>,
+    items: BTreeMap<Uid, BTreeSet<Item>>,
+    firsts: HashMap<Vec<Token>, BTreeSet<Token>>,
+}
+
+impl<'a> Search<'a> {
+    fn new(graph: &'a Graph, grammar: &'a Grammar) -> Self {
+        let edges = graph
+            .0
+            .iter()
+            .map(|(&id, (_, neighbors))| {
+                (
+                    id,
+                    neighbors.iter().map(|(&to, &token)| (token, to)).collect(),
+                )
+            })
+            .collect();
+        let items = graph
+            .0
+            .iter()
+            .map(|(&id, (states, _))| {
+                let items = states
+                    .iter()
+                    .map(|x| Item {
+                        rule: x.rule,
+                        before: x.before.clone(),
+                        after: x.after.clone(),
+                    })
+                    .collect();
+                (id, items)
+            })
+            .collect();
+        Search {
+            grammar,
+            edges,
+            items,
+            firsts: HashMap::new(),
+        }
+    }
+
+    fn first(&mut self, tokens: &[Token]) -> BTreeSet<Token> {
+        if let Some(first) = self.firsts.get(tokens) {
+            return first.clone();
+        }
+        let first = self.grammar.first(tokens);
+        self.firsts.insert(tokens.to_vec(), first.clone());
+        first
+    }
+
+    /// the items `start -> . entry $` of all start rules and the states they live in
+    fn starts(&self) -> Vec<(Uid, Item)> {
+        let grammar = self.grammar;
+        self.items
+            .iter()
+            .flat_map(|(&id, items)| {
+                items
+                    .iter()
+                    .filter(move |x| grammar.is_start(x.rule) && x.before.is_empty())
+                    .map(move |x| (id, x.clone()))
+            })
+            .sorted_by_key(|x| x.0)
+            .collect()
+    }
+
+    /// Finds the shortest derivation reaching `target` in `state`. If `follow` is given, the
+    /// lookahead after the target's rule has to be that token. If `path` is given, the derivation
+    /// has to pass through exactly these states.
+    fn find(
+        &mut self,
+        state: Uid,
+        target: &Item,
+        follow: Option<Token>,
+        path: Option<&[Uid]>,
+    ) -> Option<Derivation> {
+        let mut parents: HashMap<Node, Option<(Node, Step)>> = HashMap::new();
+        let mut queue = VecDeque::new();
+        for (start_state, item) in self.starts() {
+            if path.is_some_and(|p| p.first() != Some(&start_state)) {
+                continue;
+            }
+            let node = Node {
+                at: if path.is_some() { 0 } else { start_state },
+                item,
+                follow: follow.map(|_| Token::Eof),
+            };
+            parents.insert(node.clone(), None);
+            queue.push_back(node);
+        }
+
+        while let Some(node) = queue.pop_front() {
+            if parents.len() > SEARCH_LIMIT {
+                return None;
+            }
+            let current = match path {
+                Some(p) => p[node.at],
+                None => node.at,
+            };
+            let at_end = path.is_none_or(|p| node.at + 1 == p.len());
+            if current == state && at_end && node.item == *target && node.follow == follow {
+                return Some(self.reconstruct(node, &parents));
+            }
+
+            let Some((&next, rest)) = node.item.after.split_first() else {
+                continue;
+            };
+
+            let mut successors = Vec::new();
+            if let Some(&to) = self.edges.get(&current).and_then(|x| x.get(&next)) {
+                let at = match path {
+                    Some(p) if p.get(node.at + 1) == Some(&to) => Some(node.at + 1),
+                    Some(_) => None,
+                    None => Some(to),
+                };
+                if let Some(at) = at {
+                    successors.push((
+                        Node {
+                            at,
+                            item: node.item.advance(),
+                            follow: node.follow,
+                        },
+                        Step::Shift(to),
+                    ));
+                }
+            }
+
+            if let Token::NonTerm(non_term) = next {
+                let follows = match node.follow {
+                    None => vec![None],
+                    Some(follow) => {
+                        let mut first = self.first(rest);
+                        if first.remove(&Token::Empty) {
+                            first.insert(follow);
+                        }
+                        first.into_iter().map(Some).collect()
+                    }
+                };
+                for production in self.grammar.productions(non_term) {
+                    for &follow in &follows {
+                        let item = Item {
+                            rule: non_term,
+                            before: Vec::new(),
+                            after: production.to_vec(),
+                        };
+                        successors.push((
+                            Node {
+                                at: node.at,
+                                item,
+                                follow,
+                            },
+                            Step::Produce,
+                        ));
+                    }
+                }
+            }
+
+            for (successor, step) in successors {
+                if parents.contains_key(&successor) {
+                    continue;
+                }
+                parents.insert(successor.clone(), Some((node.clone(), step)));
+                queue.push_back(successor);
+            }
+        }
+
+        None
+    }
+
+    fn reconstruct(&self, node: Node, parents: &HashMap<Node, Option<(Node, Step)>>) -> Derivation {
+        let mut steps = Vec::new();
+        let mut current = node;
+        while let Some((parent, step)) = parents[&current].clone() {
+            steps.push((step, current.item));
+            current = parent;
+        }
+        steps.reverse();
+        let start_state = self
+            .starts()
+            .into_iter()
+            .find(|(_, item)| *item == current.item)
+            .map(|x| x.0)
+            .expect("the search always begins at a start item");
+        Derivation::from_path(current.item, start_state, steps)
+    }
+
+    /// the item in `state` that is responsible for `action` on `token`
+    fn item_for(&self, state: Uid, token: Token, action: &Action) -> Option<Item> {
+        let items = self.items.get(&state)?;
+        match action {
+            Action::Reduce(rule, expansion) => Some(Item {
+                rule: *rule,
+                before: expansion.clone(),
+                after: Vec::new(),
+            }),
+            Action::Shift(_) => items
+                .iter()
+                .filter(|x| x.after.first() == Some(&token))
+                .min_by_key(|x| (x.before.len() + x.after.len(), x.rule))
+                .cloned(),
+        }
+        .filter(|x| items.contains(x))
+    }
+
+    fn explain(&mut self, conflict: &Conflict) -> Counterexample {
+        let derivations = (|| {
+            let (first, second) = match (&conflict.either, &conflict.or) {
+                (shift @ Action::Shift(_), reduce) | (reduce, shift @ Action::Shift(_)) => {
+                    (reduce, shift)
+                }
+                (a, b) => (a, b),
+            };
+            let state = conflict.state;
+            let token = conflict.token;
+            let first_item = self.item_for(state, token, first)?;
+            let second_item = self.item_for(state, token, second)?;
+            // after merging states, the lookahead might not be reachable exactly. Fall back to
+            // ignoring lookaheads, which still shows how the state is reached
+            let first = self
+                .find(state, &first_item, Some(token), None)
+                .or_else(|| self.find(state, &first_item, None, None))?;
+            let path = first.states.clone();
+            let follow = matches!(second, Action::Reduce(..)).then_some(token);
+            let second = self
+                .find(state, &second_item, follow, Some(&path))
+                .or_else(|| self.find(state, &second_item, None, Some(&path)))?;
+            Some((first, second))
+        })();
+
+        Counterexample {
+            conflict: conflict.clone(),
+            derivations,
+        }
+    }
+}
+
+/// searches counterexamples for all `conflicts`
+pub fn explain(graph: &Graph, grammar: &Grammar, conflicts: &[Conflict]) -> Vec<Counterexample> {
+    let mut search = Search::new(graph, grammar);
+    conflicts.iter().map(|x| search.explain(x)).collect()
+}
+
+impl Counterexample {
+    /// whether both derivations produce the same sentence, which proves the grammar ambiguous
+    pub fn is_unifying(&self) -> bool {
+        self.derivations
+            .as_ref()
+            .is_some_and(|(a, b)| a.sentence() == b.sentence())
+    }
+
+    pub fn display<'a>(&'a self, pool: &'a Pool) -> CounterexampleDisplay<'a> {
+        CounterexampleDisplay {
+            example: self,
+            pool,
+        }
+    }
+}
+
+pub struct CounterexampleDisplay<'a> {
+    example: &'a Counterexample,
+    pool: &'a Pool,
+}
+
+fn write_sentence(
+    f: &mut std::fmt::Formatter<'_>,
+    pool: &Pool,
+    derivation: &Derivation,
+) -> std::fmt::Result {
+    let (before, after) = derivation.sentence();
+    for token in before {
+        write!(f, "{} ", token.display(pool))?;
+    }
+    write!(f, "•")?;
+    for token in after {
+        write!(f, " {}", token.display(pool))?;
+    }
+    Ok(())
+}
+
+fn write_derivation(
+    f: &mut std::fmt::Formatter<'_>,
+    pool: &Pool,
+    items: &[Item],
+) -> std::fmt::Result {
+    let Some((item, inner)) = items.split_first() else {
+        return Ok(());
+    };
+    write!(f, "{} → [", pool.get(item.rule))?;
+    for token in &item.before {
+        write!(f, " {}", token.display(pool))?;
+    }
+    if inner.is_empty() {
+        write!(f, " •")?;
+        for token in &item.after {
+            write!(f, " {}", token.display(pool))?;
+        }
+    } else {
+        write!(f, " ")?;
+        write_derivation(f, pool, inner)?;
+        for token in item.after.iter().skip(1) {
+            write!(f, " {}", token.display(pool))?;
+        }
+    }
+    write!(f, " ]")
+}
+
+impl Display for CounterexampleDisplay<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pool = self.pool;
+        let conflict = &self.example.conflict;
+        let Some((first, second)) = &self.example.derivations else {
+            return writeln!(f, "    no counterexample found");
+        };
+        // the start rule is an implementation detail, leave it out
+        let (first_items, second_items) = (&first.items[1..], &second.items[1..]);
+
+        if self.example.is_unifying() {
+            write!(f, "    ambiguous example: ")?;
+            write_sentence(f, pool, first)?;
+            writeln!(f)?;
+        } else {
+            write!(f, "    first example:  ")?;
+            write_sentence(f, pool, first)?;
+            writeln!(f)?;
+            write!(f, "    second example: ")?;
+            write_sentence(f, pool, second)?;
+            writeln!(f)?;
+        }
+
+        let [a, b] = match (&conflict.either, &conflict.or) {
+            (shift @ Action::Shift(_), reduce) | (reduce, shift @ Action::Shift(_)) => {
+                [reduce, shift]
+            }
+            (a, b) => [a, b],
+        };
+        for (action, items) in [(a, first_items), (b, second_items)] {
+            match action {
+                Action::Reduce(..) => writeln!(f, "    reduce derivation:")?,
+                Action::Shift(_) => writeln!(f, "    shift derivation:")?,
+            }
+            write!(f, "        ")?;
+            write_derivation(f, pool, items)?;
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::Table;
+
+    #[test]
+    fn ambiguous_expression() {
+        let g = crate::grammar!(
+            E:
+            E => N "E" T "plus" N "E" @ "";
+            E => T "x" @ "";
+        );
+        let graph = Graph::make(&g);
+        let conflicts = Table::from_graph(&graph, &g).unwrap_err();
+        let examples = explain(&graph, &g, &conflicts.0);
+        assert_eq!(examples.len(), 1);
+        assert!(examples[0].is_unifying());
+        let rendered = examples[0].display(g.pool()).to_string();
+        assert!(rendered.contains("ambiguous example: E `plus` E • `plus` E"));
+        assert!(rendered.contains("E → [ E → [ E `plus` E • ] `plus` E ]"));
+        assert!(rendered.contains("E → [ E `plus` E → [ E • `plus` E ] ]"));
+    }
+}
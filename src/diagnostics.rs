@@ -0,0 +1,375 @@
+//! Errors and warnings about a grammar file, rendered in the style of rustc:
+//!
+//! ```text
+//! error: unexpected `:`
+//!  --> grammar.ast:5:9
+//!   |
+//! 5 |   | Expr : Num { () }
+//!   |          ^ expected one of identifier, code literal or `%prec`
+//! ```
+
+use std::{collections::BTreeMap, fmt::Display, io::IsTerminal};
+
+use ansi_term::{Color, Style};
+
+use crate::source::{Source, Span};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// Underlines a part of the grammar file. The primary labels say where a diagnostic happened,
+/// secondary labels point at related places.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Label {
+    pub span: Span,
+    pub message: String,
+    pub primary: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub labels: Vec<Label>,
+    pub notes: Vec<String>,
+    /// suggestions how to fix the problem, shown after the notes
+    pub help: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(Severity::Error, message.into())
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(Severity::Warning, message.into())
+    }
+
+    fn new(severity: Severity, message: String) -> Self {
+        Self {
+            severity,
+            message,
+            labels: Vec::new(),
+            notes: Vec::new(),
+            help: Vec::new(),
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+
+    /// adds a primary label
+    pub fn label(mut self, span: Span, message: impl Into<String>) -> Self {
+        self.labels.push(Label {
+            span,
+            message: message.into(),
+            primary: true,
+        });
+        self
+    }
+
+    /// adds a secondary label
+    pub fn secondary(mut self, span: Span, message: impl Into<String>) -> Self {
+        self.labels.push(Label {
+            span,
+            message: message.into(),
+            primary: false,
+        });
+        self
+    }
+
+    /// adds a note below the source snippet. Notes may span multiple lines
+    pub fn note(mut self, message: impl Into<String>) -> Self {
+        self.notes.push(message.into());
+        self
+    }
+
+    /// adds a suggestion below the notes
+    pub fn help(mut self, message: impl Into<String>) -> Self {
+        self.help.push(message.into());
+        self
+    }
+
+    /// Displays the diagnostic without colors. Labels are only shown if `source` is given, it has
+    /// to be the file their spans refer to.
+    pub fn display<'a>(&'a self, source: Option<Source<'a>>) -> DiagnosticDisplay<'a> {
+        DiagnosticDisplay {
+            diagnostic: self,
+            source,
+            color: false,
+        }
+    }
+
+    /// prints the diagnostic to stderr, colored if stderr is a terminal and `NO_COLOR` is not set
+    pub fn emit(&self, source: Option<Source>) {
+        let display = DiagnosticDisplay {
+            color: use_color(),
+            ..self.display(source)
+        };
+        eprintln!("{display}\n");
+    }
+}
+
+/// only shows the message, the rest needs the grammar file. See [`Diagnostic::display`]
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for Diagnostic {}
+
+/// see <https://no-color.org>
+fn use_color() -> bool {
+    std::env::var_os("NO_COLOR").is_none_or(|x| x.is_empty()) && std::io::stderr().is_terminal()
+}
+
+pub struct DiagnosticDisplay<'a> {
+    diagnostic: &'a Diagnostic,
+    source: Option<Source<'a>>,
+    color: bool,
+}
+
+/// a label resolved to the line it starts on
+struct Mark<'a> {
+    col: usize,
+    width: usize,
+    label: &'a Label,
+}
+
+impl DiagnosticDisplay<'_> {
+    fn style(&self, style: Style) -> Style {
+        if self.color {
+            style
+        } else {
+            Style::new()
+        }
+    }
+
+    fn severity_style(&self) -> Style {
+        self.style(match self.diagnostic.severity {
+            Severity::Error => Color::Red.bold(),
+            Severity::Warning => Color::Yellow.bold(),
+        })
+    }
+
+    /// groups the labels by the line they start on
+    fn lines(&self, source: Source) -> BTreeMap<usize, Vec<Mark<'_>>> {
+        let mut lines: BTreeMap<usize, Vec<Mark>> = BTreeMap::new();
+        for label in &self.diagnostic.labels {
+            let (line, col) = source.line_col(label.span.start);
+            let line_span = source.line_span(label.span.start);
+            // labels spanning several lines are cut off at the end of the first one
+            let end = label.span.end.clamp(line_span.start, line_span.end);
+            let start = label.span.start.min(end);
+            let width = source.text()[start..end].chars().count().max(1);
+            lines
+                .entry(line)
+                .or_default()
+                .push(Mark { col, width, label });
+        }
+        for marks in lines.values_mut() {
+            marks.sort_by_key(|x| x.col);
+        }
+        lines
+    }
+}
+
+impl Display for DiagnosticDisplay<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let diagnostic = self.diagnostic;
+        let severity = match diagnostic.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        write!(
+            f,
+            "{}{}",
+            self.severity_style().paint(severity),
+            self.style(Style::new().bold())
+                .paint(format!(": {}", diagnostic.message))
+        )?;
+
+        let gutter = self.style(Color::Blue.bold());
+        let source = self.source.filter(|_| !diagnostic.labels.is_empty());
+        let lines = source.map(|x| self.lines(x)).unwrap_or_default();
+        let width = lines.keys().last().map_or(0, |x| x.to_string().len());
+        let pad = " ".repeat(width);
+
+        if let Some(source) = source {
+            let main = diagnostic
+                .labels
+                .iter()
+                .find(|x| x.primary)
+                .unwrap_or(&diagnostic.labels[0]);
+            let (line, col) = source.line_col(main.span.start);
+            writeln!(f)?;
+            writeln!(
+                f,
+                "{pad}{} {}:{line}:{col}",
+                gutter.paint("-->"),
+                source.name()
+            )?;
+            write!(f, "{pad} {}", gutter.paint("|"))?;
+
+            let mut previous = None;
+            for (&line, marks) in &lines {
+                if previous.is_some_and(|x| line > x + 1) {
+                    write!(f, "\n{}", gutter.paint("..."))?;
+                }
+                previous = Some(line);
+
+                let line_span = source.line_span(marks[0].label.span.start);
+                let text = &source.text()[line_span.start..line_span.end];
+                write!(f, "\n{} {text}", gutter.paint(format!("{line:>width$} |")))?;
+                for mark in marks {
+                    let (style, underline) = if mark.label.primary {
+                        (self.severity_style(), "^")
+                    } else {
+                        (gutter, "-")
+                    };
+                    let mut underline = underline.repeat(mark.width);
+                    if !mark.label.message.is_empty() {
+                        underline = format!("{underline} {}", mark.label.message);
+                    }
+                    write!(
+                        f,
+                        "\n{pad} {} {}{}",
+                        gutter.paint("|"),
+                        " ".repeat(mark.col - 1),
+                        style.paint(underline)
+                    )?;
+                }
+            }
+        }
+
+        let notes = diagnostic.notes.iter().map(|x| ("note:", x));
+        let help = diagnostic.help.iter().map(|x| ("help:", x));
+        for (kind, note) in notes.chain(help) {
+            let mut note_lines = note.lines();
+            write!(
+                f,
+                "\n{pad} {} {}",
+                gutter.paint("="),
+                self.style(Style::new().bold()).paint(kind)
+            )?;
+            if let Some(first) = note_lines.next() {
+                write!(f, " {first}")?;
+            }
+            for line in note_lines {
+                write!(f, "\n{pad}         {line}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The number of single character insertions, deletions, substitutions and swaps of adjacent
+/// characters that turn `a` into `b`
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    // distances[i][j] is the distance between the first i characters of a and j characters of b
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    distances[0] = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let substitute = distances[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = substitute
+                .min(distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+    distances[a.len()][b.len()]
+}
+
+/// The candidate that `name` is most likely a misspelling of, if up to a third of its characters
+/// are wrong
+pub fn suggest<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let limit = (name.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .filter(|&x| x != name)
+        .map(|x| (edit_distance(name, x), x))
+        .filter(|&(distance, _)| distance <= limit)
+        .min()
+        .map(|(_, x)| x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRAMMAR: &str = "Expr: { () }\n  | Expr Plus Expr { () }\n\n  | Num { () }\n";
+
+    #[test]
+    fn primary_and_secondary_labels() {
+        let source = Source::new("test.ast", GRAMMAR);
+        let diagnostic = Diagnostic::error("conflict on `Plus`")
+            .secondary(Span::new(17, 21), "")
+            .label(Span::new(22, 26), "shifted here")
+            .secondary(Span::new(44, 47), "reduced here")
+            .note("first line\nsecond line");
+        assert_eq!(
+            diagnostic.display(Some(source)).to_string(),
+            "\
+error: conflict on `Plus`
+ --> test.ast:2:10
+  |
+2 |   | Expr Plus Expr { () }
+  |     ----
+  |          ^^^^ shifted here
+...
+4 |   | Num { () }
+  |     --- reduced here
+  = note: first line
+          second line"
+        );
+    }
+
+    #[test]
+    fn without_source() {
+        let diagnostic = Diagnostic::warning("unused rule")
+            .label(Span::new(0, 4), "never used")
+            .help("remove it")
+            .note("it is never used");
+        assert_eq!(
+            diagnostic.display(None).to_string(),
+            "warning: unused rule\n = note: it is never used\n = help: remove it"
+        );
+        assert_eq!(diagnostic.to_string(), "unused rule");
+    }
+
+    #[test]
+    fn multiline_spans_are_cut_off() {
+        let source = Source::new("test.ast", GRAMMAR);
+        let diagnostic = Diagnostic::error("bad rule").label(Span::new(0, 40), "");
+        assert!(diagnostic
+            .display(Some(source))
+            .to_string()
+            .ends_with("1 | Expr: { () }\n  | ^^^^^^^^^^^^"));
+    }
+
+    #[test]
+    fn suggestions() {
+        assert_eq!(edit_distance("EXPR_MLU", "EXPR_MUL"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        let rules = ["EXPR_MUL", "EXPR_ADD", "Ident"];
+        assert_eq!(suggest("EXPR_MLU", rules), Some("EXPR_MUL"));
+        assert_eq!(suggest("Idnet", rules), Some("Ident"));
+        assert_eq!(suggest("IDENT", rules), None);
+        assert_eq!(suggest("Plus", rules), None);
+        assert_eq!(suggest("Ident", rules), None);
+    }
+}
@@ -1,13 +1,14 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashMap},
     fmt::{Display, Formatter, Result},
 };
 
-use itertools::Itertools;
+use anyhow::bail;
 
 use crate::{
+    diagnostics::Diagnostic,
     generator::Uid,
-    grammar::{Grammar, Token},
+    grammar::{Grammar, GrammarEntry, Token},
     string_pool::Id,
     table::{Action, Table},
 };
@@ -18,11 +19,18 @@ pub struct Ctx<'a> {
 
 pub mod ocaml;
 pub mod rust;
+pub mod rust_table;
 pub mod python;
 // pub mod java;
 
 /// Visitor trait. This is to be implemented for every target language.
 pub trait Visitor {
+    /// Checks that the visitor can render `grammar`, before anything is written. Errors here
+    /// would otherwise only show up as panics halfway through rendering.
+    fn check(&self, _grammar: &Grammar) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     /// This function is called before the table is "entered", i.e. at the very beginning
     /// It is only called once per writing process.
     fn before_enter(&self, ctx: &Ctx, f: &mut Formatter, all_states: &[Uid]) -> Result;
@@ -65,13 +73,24 @@ pub trait Visitor {
         rule: Id,
         expansion: &[Token],
     ) -> Result;
-    /// This function is used to handle a matching error.
+    /// This function is used to encode the code of a production, so that [`visit_reduce`] only
+    /// has to refer to it. It is called once per production, with its index in
+    /// [`Grammar::entries`], after the goto tables are set up and before the parse loop.
+    fn visit_production(
+        &self,
+        ctx: &Ctx,
+        f: &mut Formatter,
+        index: usize,
+        entry: &GrammarEntry,
+    ) -> Result;
+    /// This function is used to handle a matching error. `expected` are the tokens the state has
+    /// an action for.
     fn matching_error(
         &self,
         ctx: &Ctx,
         f: &mut Formatter,
         state: Uid,
-        expected: HashSet<Token>,
+        expected: BTreeSet<Token>,
     ) -> Result;
     /// This function is used to set up the goto tables
     fn visit_goto(
@@ -104,29 +123,27 @@ impl Display for Render<'_> {
             grammar: self.grammar,
         };
         let v = &self.v;
-        let all_states: Vec<_> = self.table.0.keys().copied().sorted().collect();
+        let all_states: Vec<_> = self.table.0.keys().copied().collect();
         v.before_enter(&ctx, f, all_states.as_slice())?;
-        let mut gotos = HashMap::new();
-        for (node_id, entry) in &self.table.0 {
+        let mut gotos: BTreeMap<Id, BTreeMap<Uid, Uid>> = BTreeMap::new();
+        for (&node_id, entry) in &self.table.0 {
             for (&sym, &goto) in &entry.gotos {
-                gotos
-                    .entry(sym)
-                    .and_modify(|x: &mut HashMap<usize, usize>| {
-                        x.insert(*node_id, goto);
-                    })
-                    .or_insert_with(|| HashMap::from([(*node_id, goto)]));
+                gotos.entry(sym).or_default().insert(node_id, goto);
             }
         }
-        for (symbol, goto) in gotos.into_iter().sorted_by_key(|x| x.0) {
-            v.visit_goto(&ctx, f, symbol, &mut goto.into_iter().sorted())?;
+        for (symbol, goto) in gotos {
+            v.visit_goto(&ctx, f, symbol, &mut goto.into_iter())?;
+        }
+        for (index, entry) in self.grammar.entries().iter().enumerate() {
+            v.visit_production(&ctx, f, index, entry)?;
         }
 
         v.begin_parse_loop(&ctx, f)?;
 
-        for (&node_id, entry) in self.table.0.iter().sorted_by_key(|x| x.0) {
+        for (&node_id, entry) in &self.table.0 {
             v.enter_state(&ctx, f, node_id)?;
 
-            for (&tok, action) in entry.actions.iter().sorted() {
+            for (&tok, action) in &entry.actions {
                 v.enter_match(&ctx, f, node_id, tok)?;
                 match action {
                     Action::Reduce(rule, expansion) => {
@@ -143,7 +160,7 @@ impl Display for Render<'_> {
                 &ctx,
                 f,
                 node_id,
-                entry.actions.iter().map(|(&x, _)| x).collect(),
+                entry.actions.keys().copied().collect(),
             )?;
 
             v.leave_state(&ctx, f, node_id)?;
@@ -158,15 +175,60 @@ impl Display for Render<'_> {
 }
 
 impl<'a> Render<'a> {
-    pub fn new(v: &'a dyn Visitor, table: &'a Table, grammar: &'a Grammar) -> Self {
-        Render { v, table, grammar }
+    /// fails if the visitor can not render `grammar`, see [`Visitor::check`]
+    pub fn new(v: &'a dyn Visitor, table: &'a Table, grammar: &'a Grammar) -> anyhow::Result<Self> {
+        v.check(grammar)?;
+        Ok(Render { v, table, grammar })
     }
 }
 
+/// The entry points of `grammar` in the order they were given, as the start rule, the entry rule it
+/// derives and the state its parser begins in
+fn entry_points(grammar: &Grammar) -> impl Iterator<Item = (Id, Id, Uid)> + '_ {
+    grammar
+        .starts()
+        .iter()
+        .enumerate()
+        .map(|(i, &start)| (start, grammar.entry_rule(start), i + 1))
+}
+
+/// The type of the values `rule` produces, according to `types`. A start rule produces the value
+/// of its entry rule.
+fn rule_type<'a>(
+    grammar: &Grammar,
+    types: &'a HashMap<String, String>,
+    rule: Id,
+) -> Option<&'a String> {
+    let rule = match grammar.is_start(rule) {
+        true => grammar.entry_rule(rule),
+        false => rule,
+    };
+    types.get(grammar.pool().get(rule))
+}
+
+/// fails if a rule of `grammar` has no type in `types`. Start rules have the type of their entry
+/// rule, which [`Grammar::analyze`] already reports if it is not defined.
+fn check_types(grammar: &Grammar, types: &HashMap<String, String>) -> anyhow::Result<()> {
+    for entry in grammar.entries() {
+        if grammar.is_start(entry.rule_name()) {
+            continue;
+        }
+        if rule_type(grammar, types, entry.rule_name()).is_none() {
+            let name = grammar.pool().get(entry.rule_name());
+            bail!(Diagnostic::error(format!("rule `{name}` has no type")));
+        }
+    }
+    Ok(())
+}
+
 impl<V> Visitor for Box<V>
 where
     V: Visitor + ?Sized,
 {
+    fn check(&self, grammar: &Grammar) -> anyhow::Result<()> {
+        (**self).check(grammar)
+    }
+
     fn before_enter(&self, ctx: &Ctx, f: &mut Formatter, all_states: &[Uid]) -> Result {
         (**self).before_enter(ctx, f, all_states)
     }
@@ -222,12 +284,22 @@ where
         (**self).visit_reduce(ctx, f, state, token, rule, expansion)
     }
 
+    fn visit_production(
+        &self,
+        ctx: &Ctx,
+        f: &mut Formatter,
+        index: usize,
+        entry: &GrammarEntry,
+    ) -> Result {
+        (**self).visit_production(ctx, f, index, entry)
+    }
+
     fn matching_error(
         &self,
         ctx: &Ctx,
         f: &mut Formatter,
         state: Uid,
-        expected: HashSet<Token>,
+        expected: BTreeSet<Token>,
     ) -> Result {
         (**self).matching_error(ctx, f, state, expected)
     }
@@ -75,7 +75,7 @@ impl Visitor for Java {
         ctx: &super::Ctx,
         f: &mut std::fmt::Formatter,
         state: crate::generator::Uid,
-        expected: std::collections::HashSet<crate::grammar::Token>,
+        expected: std::collections::BTreeSet<crate::grammar::Token>,
     ) -> std::fmt::Result {
         todo!()
     }
@@ -3,43 +3,41 @@ use std::collections::HashMap;
 use anyhow::Context;
 use itertools::Itertools;
 
-use crate::{generator::Uid, grammar::Token, string_pool::Id};
+use crate::{
+    generator::Uid,
+    grammar::{Grammar, GrammarEntry, Token},
+    string_pool::Id,
+};
 
-use super::{Ctx, Format, Visitor};
+use super::{check_types, entry_points, rule_type, Ctx, Format, Visitor};
 
 pub struct OcamlVisitor {
     prelude: String,
     non_terminal_types: HashMap<String, String>,
     terminal_types: HashMap<String, String>,
     token_type: String,
-    entry_rule: String,
 }
 
 impl OcamlVisitor {
     pub fn new(
         prelude: String,
-        mut non_terminal_types: HashMap<String, String>,
+        non_terminal_types: HashMap<String, String>,
         terminal_types: HashMap<String, String>,
-        entry_rule: String,
     ) -> Self {
-        non_terminal_types.insert(
-            "S0".to_owned(),
-            non_terminal_types
-                .get(&entry_rule)
-                .expect("entry rule is missing in types")
-                .to_owned(),
-        );
         OcamlVisitor {
             prelude,
             non_terminal_types,
             terminal_types,
             token_type: "token".to_owned(),
-            entry_rule: "S0".to_owned(),
         }
     }
 }
 
 impl Visitor for OcamlVisitor {
+    fn check(&self, grammar: &Grammar) -> anyhow::Result<()> {
+        check_types(grammar, &self.non_terminal_types)
+    }
+
     fn enter_state(&self, _ctx: &Ctx, f: &mut std::fmt::Formatter, state: Uid) -> std::fmt::Result {
         writeln!(
             f,
@@ -96,12 +94,44 @@ impl Visitor for OcamlVisitor {
         _token: Token,
         rule: Id,
         expansion: &[Token],
+    ) -> std::fmt::Result {
+        let grammar = ctx.grammar;
+        let rule_name = grammar.pool().get(rule);
+        let index = grammar
+            .entry_index(rule, expansion)
+            .expect("reductions refer to productions of the grammar");
+
+        writeln!(f, "      let _value, _stack = reduce_{index} _stack in")?;
+
+        if grammar.is_start(rule) {
+            writeln!(f, "      _value")?;
+            return Ok(());
+        }
+
+        writeln!(f, "      let (before, _, _) = List.hd _stack in")?;
+        writeln!(f, "      let goto, goto_id = goto_{rule_name} before in")?;
+        writeln!(
+            f,
+            "      let _stack = (goto_id, NonTerm NonTerm_{rule_name}, _value) :: _stack in"
+        )?;
+        writeln!(f, "      goto _stack input")?;
+
+        Ok(())
+    }
+
+    fn visit_production(
+        &self,
+        ctx: &Ctx,
+        f: &mut std::fmt::Formatter,
+        index: usize,
+        entry: &GrammarEntry,
     ) -> std::fmt::Result {
         let grammar = ctx.grammar;
         let pool = grammar.pool();
-        let rule_name = pool.get(rule);
+        let rule_name = pool.get(entry.rule_name());
 
-        for (i, token) in expansion.iter().enumerate().rev() {
+        writeln!(f, "  and reduce_{index} (_stack: stack) =")?;
+        for (i, token) in entry.tokens().iter().enumerate().rev() {
             writeln!(f, "      let (_, typ, tmp), _stack = pop_stack _stack in")?;
             writeln!(f, "      let v{i} = (match typ, tmp with")?;
             write!(f, "      | ")?;
@@ -144,25 +174,21 @@ impl Visitor for OcamlVisitor {
 
             writeln!(f, "      ignore v{i};")?;
         }
-
-        let code = grammar
-            .entries()
-            .iter()
-            .find(|x| x.rule_name() == rule && x.tokens() == expansion)
-            .unwrap()
-            .code();
-
-        writeln!(f, "      let _value = ({code}) in")?;
-
-        if rule_name == self.entry_rule {
-            writeln!(f, "       v0")?;
-            return Ok(());
+        // after the pops, so a name can't shadow the stack
+        for i in 0..entry.tokens().len() {
+            if let Some(name) = entry.name(i) {
+                writeln!(f, "      let {name} = v{i} in")?;
+                writeln!(f, "      ignore {name};")?;
+            }
         }
 
-        writeln!(f, "      let (before, _, _) = List.hd _stack in")?;
-        writeln!(f, "      let goto, goto_id = goto_{rule_name} before in")?;
-        writeln!(f, "      let _stack = (goto_id, NonTerm NonTerm_{rule_name}, StackValue_Nonterm_{rule_name} _value) :: _stack in")?;
-        writeln!(f, "      goto _stack input")?;
+        if grammar.is_start(entry.rule_name()) {
+            writeln!(f, "      (StackValue_Nonterm_{rule_name} v0, _stack)")?;
+        } else {
+            let code = entry.code();
+            writeln!(f, "      (StackValue_Nonterm_{rule_name} ({code}), _stack)")?;
+        }
+        writeln!(f)?;
 
         Ok(())
     }
@@ -172,7 +198,7 @@ impl Visitor for OcamlVisitor {
         _ctx: &Ctx,
         f: &mut std::fmt::Formatter,
         state: Uid,
-        _expected: std::collections::HashSet<Token>,
+        _expected: std::collections::BTreeSet<Token>,
     ) -> std::fmt::Result {
         writeln!(
             f,
@@ -221,7 +247,8 @@ impl Visitor for OcamlVisitor {
                 f,
                 "       | StackValue_Nonterm_{} of ({})",
                 name,
-                self.non_terminal_types.get(name).expect("undefined type")
+                rule_type(grammar, &self.non_terminal_types, state)
+                    .expect("types are checked before rendering")
             )?;
         }
 
@@ -262,7 +289,7 @@ impl Visitor for OcamlVisitor {
         writeln!(
             f,
             r#"
-            let parse input =
+            let _parse (start: int) input =
 
             let raise_msg m = raise (Parse_error (ErrMsg (m))) in
             let pop msg = function
@@ -278,20 +305,31 @@ impl Visitor for OcamlVisitor {
     }
 
     fn after_leave(&self, ctx: &Ctx, f: &mut std::fmt::Formatter, _: &[Uid]) -> std::fmt::Result {
-        writeln!(
-            f,
-            r#"
-        in
-        node1 [ State_1, NonTerm NonTerm_{}, StackValue_None ] input
-        "#,
-            ctx.grammar.pool().get(
-                ctx.grammar
-                    .entries()
-                    .last()
-                    .expect("grammar was empty, this should never happen")
-                    .rule_name()
-            )
-        )?;
+        let pool = ctx.grammar.pool();
+        writeln!(f, "\n        in\n        match start with")?;
+        for (start, _, state) in entry_points(ctx.grammar) {
+            writeln!(
+                f,
+                "        | {state} -> node{state} [ State_{state}, NonTerm NonTerm_{}, StackValue_None ] input",
+                pool.get(start)
+            )?;
+        }
+        writeln!(f, r#"        | _ -> raise_msg ("unknown start state")"#)?;
+
+        // every entry rule gets its own function, they all run the same states
+        for (start, entry, state) in entry_points(ctx.grammar) {
+            let (start, entry) = (pool.get(start), pool.get(entry));
+            writeln!(
+                f,
+                r#"
+        let parse_{entry} input =
+          match _parse {state} input with
+          | StackValue_Nonterm_{start} v -> v
+          | _ -> raise (Parse_error (ErrMsg "parsed the wrong rule. this is probably a bug"))"#
+            )?;
+        }
+        let first = ctx.grammar.entry_rule(ctx.grammar.starts()[0]);
+        writeln!(f, "\n        let parse = parse_{}", pool.get(first))?;
         Ok(())
     }
 
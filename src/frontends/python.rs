@@ -3,7 +3,7 @@ use std::cell::Cell;
 use itertools::Itertools;
 use textwrap::dedent;
 
-use super::{Format, Visitor};
+use super::{entry_points, Format, Visitor};
 
 #[derive(Debug)]
 pub struct Python {
@@ -48,19 +48,13 @@ impl Format for Python {
 impl Python {
     pub fn new(
         prelude: String,
-        _entry_rule: String,
         gen_token_fn: Option<String>,
         get_data: String,
         get_kind: String,
         token_kind: String,
     ) -> Self {
-        assert!(
-            gen_token_fn.is_none(),
-            "generating tokens is not yet handled"
-        );
         Self {
             prelude,
-            // entry_rule,
             gen_token_fn,
             get_data,
             get_kind,
@@ -216,31 +210,54 @@ impl Visitor for Python {
         indentln!(
             f,
             self,
-            "def parse({}):",
+            "def _parse({}start):",
             if self.gen_token_fn.is_some() {
                 ""
             } else {
-                "tokens"
+                "tokens, "
             }
         );
         self.enter();
 
         indentln!(f, self, "stack = []");
         indentln!(f, self, "tokens = Peekable(iter(tokens))");
-        indentln!(f, self, "state = State.STATE1");
-        indentln!(f, self, "stack.append((State.STATE1, None, None))");
+        indentln!(f, self, "state = start");
+        indentln!(f, self, "stack.append((start, None, None))");
 
         Ok(())
     }
 
     fn after_leave(
         &self,
-        _ctx: &super::Ctx,
-        _f: &mut std::fmt::Formatter,
+        ctx: &super::Ctx,
+        f: &mut std::fmt::Formatter,
         _all_states: &[crate::generator::Uid],
     ) -> std::fmt::Result {
         self.leave();
         self.first_state.set(true);
+
+        // every entry rule gets its own function, they all run the same states
+        let pool = ctx.grammar.pool();
+        let tokens = if self.gen_token_fn.is_some() {
+            ""
+        } else {
+            "tokens"
+        };
+        for (_, entry, state) in entry_points(ctx.grammar) {
+            indentln!(f, self);
+            indentln!(f, self, "def parse_{}({tokens}):", pool.get(entry));
+            self.enter();
+            let separator = if tokens.is_empty() { "" } else { ", " };
+            indentln!(
+                f,
+                self,
+                "return _parse({tokens}{separator}State.STATE{state})"
+            );
+            self.leave();
+        }
+        let first = ctx.grammar.entry_rule(ctx.grammar.starts()[0]);
+        indentln!(f, self);
+        indentln!(f, self, "parse = parse_{}", pool.get(first));
         Ok(())
     }
 
@@ -354,8 +371,33 @@ impl Visitor for Python {
         expansion: &[crate::grammar::Token],
     ) -> std::fmt::Result {
         let rule_name = ctx.grammar.pool().get(rule);
+        let index = ctx
+            .grammar
+            .entry_index(rule, expansion)
+            .expect("reductions refer to productions of the grammar");
+        if ctx.grammar.is_start(rule) {
+            indentln!(f, self, "return reduce_{index}(stack)");
+            return Ok(());
+        }
+        indentln!(f, self, "value = reduce_{index}(stack)");
+        indentln!(f, self, "before, _, _ = stack[-1]");
+        indentln!(f, self, "goto = goto_{rule_name}(before)");
+        indentln!(f, self, "state = goto");
+        indentln!(f, self, "stack.append((goto, NonTerm._{rule_name}, value))");
+        Ok(())
+    }
+
+    fn visit_production(
+        &self,
+        ctx: &super::Ctx,
+        f: &mut std::fmt::Formatter,
+        index: usize,
+        entry: &crate::grammar::GrammarEntry,
+    ) -> std::fmt::Result {
         let pool = ctx.grammar.pool();
-        for (i, token) in expansion.iter().enumerate().rev() {
+        indentln!(f, self, "def reduce_{index}(stack):");
+        self.enter();
+        for (i, token) in entry.tokens().iter().enumerate().rev() {
             indentln!(f, self, "_, expected_type, v{i} = stack.pop()");
 
             indent!(f, self, "_asterisk_assert_eq(expected_type, ");
@@ -379,22 +421,18 @@ impl Visitor for Python {
                 token.display(pool)
             )?;
         }
-        if rule_name == "S0" {
+        // after the pops, so a name can't shadow the stack
+        for i in 0..entry.tokens().len() {
+            if let Some(name) = entry.name(i) {
+                indentln!(f, self, "{name} = v{i}");
+            }
+        }
+        if ctx.grammar.is_start(entry.rule_name()) {
             indentln!(f, self, "return v0");
-            return Ok(());
+        } else {
+            indentln!(f, self, "return {}", entry.code());
         }
-        let code = ctx
-            .grammar
-            .entries()
-            .iter()
-            .find(|x| x.rule_name() == rule && x.tokens() == expansion)
-            .unwrap()
-            .code();
-        indentln!(f, self, "value = {code}");
-        indentln!(f, self, "before, _, _ = stack[-1]");
-        indentln!(f, self, "goto = goto_{rule_name}(before)");
-        indentln!(f, self, "state = goto");
-        indentln!(f, self, "stack.append((goto, NonTerm._{rule_name}, value))");
+        self.leave();
         Ok(())
     }
 
@@ -403,7 +441,7 @@ impl Visitor for Python {
         ctx: &super::Ctx,
         f: &mut std::fmt::Formatter,
         state: crate::generator::Uid,
-        expected: std::collections::HashSet<crate::grammar::Token>,
+        expected: std::collections::BTreeSet<crate::grammar::Token>,
     ) -> std::fmt::Result {
         let pool = ctx.grammar.pool();
         indentln!(f, self, "else:");
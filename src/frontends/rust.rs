@@ -4,17 +4,28 @@ use anyhow::Context;
 use itertools::Itertools;
 use tracing::info;
 
-use crate::generator::Uid;
+use crate::{
+    generator::Uid,
+    grammar::{Grammar, GrammarEntry},
+    string_pool::Id,
+};
 
-use super::{Format, Visitor};
+use super::{check_types, entry_points, rule_type, Format, Visitor};
 
 pub struct Rust {
     prelude: String,
     non_terminal_types: HashMap<String, String>,
     terminal_types: HashMap<String, String>,
     token_type: String,
-    entry_rule: String,
-    use_default_for_token: bool,
+    locations: Option<Locations>,
+}
+
+/// The source locations the parser keeps track of, if any. Tokens come with a location of type
+/// `typ`, and `merge` gives the location of several values from the first and last of them.
+/// Without `merge`, the locations have to be ranges.
+struct Locations {
+    typ: String,
+    merge: Option<String>,
 }
 impl Format for Rust {
     #[tracing::instrument(skip(self))]
@@ -32,43 +43,142 @@ impl Format for Rust {
 impl Rust {
     pub fn new(
         prelude: String,
-        mut non_terminal_types: HashMap<String, String>,
+        non_terminal_types: HashMap<String, String>,
         terminal_types: HashMap<String, String>,
-        entry_rule: String,
         token_type: String,
     ) -> Self {
-        non_terminal_types.insert(
-            "S0".to_owned(),
-            non_terminal_types
-                .get(&entry_rule)
-                .expect("entry rule is missing in types")
-                .to_owned(),
-        );
         Rust {
             prelude,
             non_terminal_types,
             terminal_types,
             token_type,
-            entry_rule,
-            use_default_for_token: false,
+            locations: None,
         }
     }
 
-    pub fn use_default_for_token(mut self) -> Self {
-        self.use_default_for_token = true;
+    /// makes the parser take a location of type `typ` with every token and keep track of the
+    /// locations of all values, see [`Locations`]
+    pub fn locations(mut self, typ: String, merge: Option<String>) -> Self {
+        self.locations = Some(Locations { typ, merge });
         self
     }
 }
 
-impl Visitor for Rust {
-    fn before_enter(
+impl Rust {
+    pub(super) fn non_terminal_types(&self) -> &HashMap<String, String> {
+        &self.non_terminal_types
+    }
+
+    pub(super) fn terminal_types(&self) -> &HashMap<String, String> {
+        &self.terminal_types
+    }
+
+    pub(super) fn token_type(&self) -> &str {
+        &self.token_type
+    }
+
+    pub(super) fn has_locations(&self) -> bool {
+        self.locations.is_some()
+    }
+
+    /// the items of the token iterator the parser takes, the tokens with their location if there
+    /// are locations
+    pub(super) fn input_type(&self) -> String {
+        match &self.locations {
+            Some(locations) => format!("({}, {})", self.token_type, locations.typ),
+            None => self.token_type.clone(),
+        }
+    }
+
+    /// The code of `entry`, with `@$`, `@N` and `@name` replaced by the locations of the
+    /// production, of its `N`th value and of the value bound to `name`. Without locations the code
+    /// is left alone, and so are references to values that don't exist, which the spec reports.
+    pub(super) fn code(&self, entry: &GrammarEntry) -> String {
+        if self.locations.is_none() {
+            return entry.code().to_owned();
+        }
+
+        let len = entry.tokens().len();
+        let mut code = String::new();
+        let mut copied = 0;
+        for (at, word) in location_refs(entry.code()) {
+            let location = match word.parse::<usize>() {
+                _ if word == "$" => Some("__loc".to_owned()),
+                Ok(i) if i < len => Some(format!("__loc{i}")),
+                Ok(_) => None,
+                Err(_) => (0..len)
+                    .find(|&i| entry.name(i) == Some(word))
+                    .map(|i| format!("__loc{i}")),
+            };
+            if let Some(location) = location {
+                code.push_str(&entry.code()[copied..at]);
+                code.push_str(&location);
+                copied = at + 1 + word.len();
+            }
+        }
+        code.push_str(&entry.code()[copied..]);
+        code
+    }
+
+    /// Pops the locations of the values of `entry` into `__loc0` to `__locN` and pushes the
+    /// location of the whole production, which is `__loc`. An empty production is where the
+    /// lookahead is, which only needs a `Default` location for an empty input. Does nothing
+    /// without locations.
+    pub(super) fn write_locations(
+        &self,
+        f: &mut std::fmt::Formatter,
+        entry: &GrammarEntry,
+    ) -> std::fmt::Result {
+        if self.locations.is_none() {
+            return Ok(());
+        }
+        let len = entry.tokens().len();
+        for i in (0..len).rev() {
+            writeln!(f, "let __loc{i} = locations.pop()?;")?;
+        }
+        match len {
+            0 => writeln!(f, "let __loc = locations.next.clone().unwrap_or_default();")?,
+            1 => writeln!(f, "let __loc = __loc0.clone();")?,
+            _ => writeln!(
+                f,
+                "let __loc = merge_locations(&__loc0, &__loc{});",
+                len - 1
+            )?,
+        }
+        writeln!(f, "locations.stack.push(__loc.clone());")?;
+        Ok(())
+    }
+
+    /// Binds the named values of `entry` to their names. This comes after everything the
+    /// generated code needs, so a name can't shadow it.
+    pub(super) fn write_bindings(
+        &self,
+        f: &mut std::fmt::Formatter,
+        entry: &GrammarEntry,
+    ) -> std::fmt::Result {
+        for i in 0..entry.tokens().len() {
+            if let Some(name) = entry.name(i) {
+                writeln!(f, "let {name} = v{i};")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Everything the rust backends share before the parser itself: the prelude, the error type
+    /// and the kinds of the tokens
+    pub(super) fn write_header(
         &self,
         ctx: &super::Ctx,
         f: &mut std::fmt::Formatter,
-        all_states: &[crate::generator::Uid],
     ) -> std::fmt::Result {
         let pool = ctx.grammar.pool();
         let token_type = &self.token_type;
+        let input_type = self.input_type();
+        // the location of the token that could not be parsed, `None` at the end of the input
+        let location = match &self.locations {
+            Some(locations) => format!("location: Option<{}>,", locations.typ),
+            None => String::new(),
+        };
         writeln!(f, "#[allow(non_camel_case_types)]")?;
         writeln!(f, "#[allow(non_snake_case)]")?;
         writeln!(f, "#[allow(clippy::let_unit_value)]")?;
@@ -89,28 +199,44 @@ impl Visitor for Rust {
             f,
             r#"pub enum Error{{ Msg(String),
                 UnexpectedToken{{
-                    expected: Vec<Option<{}>>,
-                    received: Option<{}>,
+                    expected: Vec<Option<TokenKind>>,
+                    received: Option<{token_type}>, {location}
                     state_id: usize,
-                    remaining_input: Vec<{token_type}> 
+                    remaining_input: Vec<{input_type}> 
                 }}
             }}
 
 
             impl std::fmt::Display for Error {{
                 fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
-                    write!(f, "{{self:?}}")
+                    let (expected, received) = match self {{
+                        Error::Msg(m) => return write!(f, "{{m}}"),
+                        Error::UnexpectedToken {{ expected, received, .. }} => (expected, received),
+                    }};
+                    match received {{
+                        Some(token) => match kind(token) {{
+                            Some(kind) => write!(f, "unexpected `{{kind}}`")?,
+                            None => write!(f, "unexpected `{{token:?}}`")?,
+                        }},
+                        None => write!(f, "unexpected end of input")?,
+                    }}
+                    let expected: Vec<String> = expected
+                        .iter()
+                        .map(|x| match x {{
+                            Some(kind) => format!("`{{kind}}`"),
+                            None => "end of input".to_owned(),
+                        }})
+                        .collect();
+                    match expected.split_last() {{
+                        None => Ok(()),
+                        Some((last, [])) => write!(f, ", expected {{last}}"),
+                        Some((last, rest)) => write!(f, ", expected one of {{}} or {{last}}", rest.join(", ")),
+                    }}
                 }}
             }}
 
             impl std::error::Error for Error {{}}
             "#,
-            if self.use_default_for_token {
-                token_type
-            } else {
-                "&'static str"
-            },
-            self.token_type,
         )?;
         writeln!(
             f,
@@ -123,25 +249,108 @@ impl Visitor for Rust {
         "#
         )?;
         writeln!(f, "pub type Result<T> = std::result::Result<T, Error>;\n")?;
-        writeln!(f, "#[derive(Debug, Clone, Copy)] enum State{{")?;
-        for state in all_states {
-            writeln!(f, "State{state},")?;
+        self.write_locations_type(f)?;
+
+        // the terminals without their data, so neither the stack nor the errors need a token value
+        writeln!(f, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]")?;
+        writeln!(f, "pub enum TokenKind {{")?;
+        for term in terminals(ctx.grammar) {
+            writeln!(f, "{},", pool.get(term))?;
         }
         writeln!(f, "}}\n")?;
 
-        // writeln!(
-        //     f,
-        //     r#"impl State {{
-        //         fn id(self) -> usize {{
-        //             match self {{"#
-        // )?;
-        //
-        // for state in all_states {
-        //     writeln!(f, "State{state} => {state},")?;
-        // }
-        //
-        // writeln!(f, "}} }} }}\n")?;
+        writeln!(f, "impl std::fmt::Display for TokenKind {{")?;
+        writeln!(
+            f,
+            "fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{"
+        )?;
+        writeln!(f, "let name = match *self {{")?;
+        for term in terminals(ctx.grammar) {
+            let name = pool.get(term);
+            writeln!(f, r#"TokenKind::{name} => "{name}","#)?;
+        }
+        writeln!(f, "}};")?;
+        writeln!(f, r#"write!(f, "{{name}}")"#)?;
+        writeln!(f, "}} }}\n")?;
+
+        writeln!(
+            f,
+            "/// the kind of `token`, `None` for tokens that are not terminals of the grammar"
+        )?;
+        writeln!(f, "#[allow(unreachable_patterns)]")?;
+        writeln!(
+            f,
+            "pub fn kind(token: &{token_type}) -> Option<TokenKind> {{"
+        )?;
+        writeln!(f, "match token {{")?;
+        for term in terminals(ctx.grammar) {
+            let name = pool.get(term);
+            let data = match self.terminal_types.contains_key(name) {
+                true => "(_)",
+                false => "",
+            };
+            writeln!(f, "{token_type}::{name}{data} => Some(TokenKind::{name}),")?;
+        }
+        writeln!(f, "_ => None,")?;
+        writeln!(f, "}} }}\n")?;
+        Ok(())
+    }
+
+    /// `Locations`, which keeps the locations of the values on the stack next to it, and
+    /// `merge_locations`
+    fn write_locations_type(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let Some(Locations { typ, merge }) = &self.locations else {
+            return Ok(());
+        };
+        let merged = match merge {
+            Some(merge) => format!("{merge}(first, last)"),
+            None => "first.start..last.end".to_owned(),
+        };
+        writeln!(
+            f,
+            r#"
+            /// the location of everything from `first` to `last`
+            fn merge_locations(first: &{typ}, last: &{typ}) -> {typ} {{
+                {merged}
+            }}
+
+            /// the locations of the values on the stack
+            struct Locations {{
+                stack: Vec<{typ}>,
+                /// where an empty production is, at the lookahead or at the last token at the end of the input. `None` until there was a token
+                next: Option<{typ}>,
+            }}
+
+            impl Locations {{
+                fn new(next: Option<&{typ}>) -> Self {{
+                    Locations {{ stack: Vec::new(), next: next.cloned() }}
+                }}
+
+                /// pushes the location of a shifted token, `None` for the end of the input, which has no location. `next` is the location of the token after it
+                fn shift(&mut self, location: Option<{typ}>, next: Option<&{typ}>) {{
+                    let Some(location) = location else {{
+                        return;
+                    }};
+                    self.next = Some(next.unwrap_or(&location).clone());
+                    self.stack.push(location);
+                }}
+
+                fn pop(&mut self) -> Result<{typ}> {{
+                    self.stack.pop().ok_or_else(|| Error::msg("location stack was empty. this is probably a bug"))
+                }}
+            }}
+            "#
+        )?;
+        Ok(())
+    }
 
+    /// the values on the stack, one variant for each rule and each terminal with data
+    pub(super) fn write_stack_values(
+        &self,
+        ctx: &super::Ctx,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        let pool = ctx.grammar.pool();
         writeln!(f, "#[allow(dead_code)] enum StackValue {{")?;
         for nonterm in ctx
             .grammar
@@ -156,21 +365,12 @@ impl Visitor for Rust {
                 f,
                 "NonTerm_{}({}),",
                 name,
-                self.non_terminal_types
-                    .get(name)
-                    .expect("undefined non terminal type")
+                rule_type(ctx.grammar, &self.non_terminal_types, nonterm)
+                    .expect("types are checked before rendering")
             )?;
         }
 
-        for term in ctx
-            .grammar
-            .entries()
-            .iter()
-            .flat_map(|x| x.tokens())
-            .filter_map(|x| x.term())
-            .sorted()
-            .dedup()
-        {
+        for term in terminals(ctx.grammar) {
             let name = pool.get(term);
             if let Some(typ) = self.terminal_types.get(name) {
                 writeln!(f, "Term_{}({typ}),", name)?;
@@ -180,6 +380,88 @@ impl Visitor for Rust {
         writeln!(f, "None\n")?;
         writeln!(f, "}}\n")?;
 
+        Ok(())
+    }
+
+    /// A `parse_{entry}` function for every entry rule and `parse` for the first of them. `parse`
+    /// gives the call that runs the parser from the state of a start rule.
+    pub(super) fn write_entry_points(
+        &self,
+        ctx: &super::Ctx,
+        f: &mut std::fmt::Formatter,
+        parse: &dyn Fn(&str, Uid) -> String,
+    ) -> std::fmt::Result {
+        let pool = ctx.grammar.pool();
+        let input_type = self.input_type();
+        // every entry rule gets its own function, they all run the same states
+        for (start, entry, state) in entry_points(ctx.grammar) {
+            let (start, entry) = (pool.get(start), pool.get(entry));
+            writeln!(
+                f,
+                "pub fn parse_{entry}<I>(tokens: I) -> Result<{}> where I: Iterator<Item = {input_type}>, {{",
+                self.non_terminal_types
+                    .get(entry)
+                    .expect("types are checked before rendering"),
+            )?;
+            writeln!(f, "match {}? {{", parse(start, state))?;
+            writeln!(f, "StackValue::NonTerm_{start}(v) => Ok(v),")?;
+            writeln!(
+                f,
+                r#"_ => Err(Error::msg("parsed the wrong rule. this is probably a bug")),"#
+            )?;
+            writeln!(f, "}} }}\n")?;
+        }
+
+        let first = ctx.grammar.entry_rule(ctx.grammar.starts()[0]);
+        let first = pool.get(first);
+        writeln!(
+            f,
+            "pub fn parse<I>(tokens: I) -> Result<{}> where I: Iterator<Item = {input_type}>, {{",
+            self.non_terminal_types
+                .get(first)
+                .expect("types are checked before rendering"),
+        )?;
+        writeln!(f, "parse_{first}(tokens) }}\n")?;
+
+        Ok(())
+    }
+}
+
+impl Visitor for Rust {
+    fn check(&self, grammar: &Grammar) -> anyhow::Result<()> {
+        check_types(grammar, &self.non_terminal_types)
+    }
+
+    fn before_enter(
+        &self,
+        ctx: &super::Ctx,
+        f: &mut std::fmt::Formatter,
+        all_states: &[crate::generator::Uid],
+    ) -> std::fmt::Result {
+        let pool = ctx.grammar.pool();
+        let input_type = self.input_type();
+        self.write_header(ctx, f)?;
+        writeln!(f, "#[derive(Debug, Clone, Copy)] enum State{{")?;
+        for state in all_states {
+            writeln!(f, "State{state},")?;
+        }
+        writeln!(f, "}}\n")?;
+
+        // writeln!(
+        //     f,
+        //     r#"impl State {{
+        //         fn id(self) -> usize {{
+        //             match self {{"#
+        // )?;
+        //
+        // for state in all_states {
+        //     writeln!(f, "State{state} => {state},")?;
+        // }
+        //
+        // writeln!(f, "}} }} }}\n")?;
+
+        self.write_stack_values(ctx, f)?;
+
         writeln!(f, "enum NonTerm {{")?;
         for non_term in ctx
             .grammar
@@ -196,7 +478,7 @@ impl Visitor for Rust {
         writeln!(f, "}}\n")?;
         writeln!(
             f,
-            "enum TokenType {{ Term ({token_type}), NonTerm(NonTerm), TermEof }}\n"
+            "enum TokenType {{ Term(TokenKind), NonTerm(NonTerm), TermEof }}\n"
         )?;
 
         writeln!(f, "struct Stack(Vec<(State, TokenType, StackValue)>);\n")?;
@@ -225,26 +507,26 @@ impl Visitor for Rust {
         "#
         )?;
 
+        self.write_entry_points(ctx, f, &|start, state| {
+            format!("_parse(tokens, State::State{state}, NonTerm::{start})")
+        })?;
+
         writeln!(
             f,
-            "pub fn parse<I>(tokens: I) -> Result<{}> where I: Iterator<Item = {token_type}>, {{",
-            self.non_terminal_types
-                .get(&self.entry_rule)
-                .expect("non_terminal_types does not contain entry rule"),
+            "fn _parse<I>(tokens: I, start: State, start_rule: NonTerm) -> Result<StackValue> where I: Iterator<Item = {input_type}>, {{",
         )?;
         writeln!(f, "let mut tokens = tokens.peekable();")?;
-        writeln!(f, "let mut state = State::State1;")?;
+        if self.has_locations() {
+            writeln!(
+                f,
+                "let mut locations = Locations::new(tokens.peek().map(|x| &x.1));"
+            )?;
+        }
+        writeln!(f, "let mut state = start;")?;
         writeln!(f, "let mut stack = Stack(Vec::new());")?;
         writeln!(
             f,
-            "stack.push(State::State1, TokenType::NonTerm(NonTerm::{}), StackValue::None);",
-            ctx.grammar.pool().get(
-                ctx.grammar
-                    .entries()
-                    .last()
-                    .expect("grammar was empty, this should never happen")
-                    .rule_name()
-            )
+            "stack.push(start, TokenType::NonTerm(start_rule), StackValue::None);"
         )?;
         Ok(())
     }
@@ -310,16 +592,14 @@ impl Visitor for Rust {
         match token {
             crate::grammar::Token::Term(id) => {
                 let name = pool.get(id);
-                writeln!(
-                    f,
-                    "Some({token_type}::{}{}) => {{",
-                    name,
-                    if self.terminal_types.contains_key(name) {
-                        "(_)"
-                    } else {
-                        ""
-                    }
-                )?;
+                let data = match self.terminal_types.contains_key(name) {
+                    true => "(_)",
+                    false => "",
+                };
+                match self.has_locations() {
+                    true => writeln!(f, "Some(({token_type}::{name}{data}, _)) => {{")?,
+                    false => writeln!(f, "Some({token_type}::{name}{data}) => {{")?,
+                }
             }
             crate::grammar::Token::Eof => {
                 // it is fine to not assign head here, as it is impossible to shift Eof
@@ -352,24 +632,41 @@ impl Visitor for Rust {
         if let crate::grammar::Token::Term(id) = token {
             let token_type = &self.token_type;
             let name = ctx.grammar.pool().get(id);
-            writeln!(f, "let head = tokens.next().unwrap();")?;
-            if self.terminal_types.contains_key(name) {
-                writeln!(
+            match (self.terminal_types.contains_key(name), self.has_locations()) {
+                (true, true) => writeln!(
                     f,
-                    "let {token_type}::{name}(value) = head else {{ unreachable!() }}; "
-                )?;
-                assert!(
-                    self.use_default_for_token,
-                    "not sure yet how to handle non defaultable tokens"
-                );
-                writeln!(f, "let head = {token_type}::{name}(Default::default());")?;
+                    "let Some(({token_type}::{name}(value), location)) = tokens.next() else {{ unreachable!() }};"
+                )?,
+                (true, false) => writeln!(
+                    f,
+                    "let Some({token_type}::{name}(value)) = tokens.next() else {{ unreachable!() }};"
+                )?,
+                (false, true) => writeln!(f, "let location = tokens.next().map(|x| x.1);")?,
+                (false, false) => writeln!(f, "tokens.next();")?,
+            }
+        }
+        if self.has_locations() {
+            match token {
+                crate::grammar::Token::Term(id)
+                    if self.terminal_types.contains_key(ctx.grammar.pool().get(id)) =>
+                {
+                    writeln!(
+                        f,
+                        "locations.shift(Some(location), tokens.peek().map(|x| &x.1));"
+                    )?
+                }
+                crate::grammar::Token::Term(_) => {
+                    writeln!(f, "locations.shift(location, tokens.peek().map(|x| &x.1));")?
+                }
+                // the end of the input has no location
+                _ => {}
             }
         }
         writeln!(f, "state = State::State{next_state};")?;
         match token {
             crate::grammar::Token::Term(id) => {
                 let name = ctx.grammar.pool().get(id);
-                write!(f, "stack.push(state, TokenType::Term(head), ")?;
+                write!(f, "stack.push(state, TokenType::Term(TokenKind::{name}), ")?;
                 if self.terminal_types.contains_key(name) {
                     write!(f, "StackValue::Term_{name}(value)")?;
                 } else {
@@ -399,7 +696,50 @@ impl Visitor for Rust {
         expansion: &[crate::grammar::Token],
     ) -> std::fmt::Result {
         let rule_name = ctx.grammar.pool().get(rule);
-        for (i, token) in expansion.iter().enumerate().rev() {
+        let index = ctx
+            .grammar
+            .entry_index(rule, expansion)
+            .expect("reductions refer to productions of the grammar");
+        match self.has_locations() {
+            true => writeln!(
+                f,
+                "let value = reduce_{index}(&mut stack, &mut locations)?;"
+            )?,
+            false => writeln!(f, "let value = reduce_{index}(&mut stack)?;")?,
+        }
+        if ctx.grammar.is_start(rule) {
+            writeln!(f, "return Ok(value);")?;
+            return Ok(());
+        }
+
+        writeln!(f, "let &(before, _, _) = stack.peek()?;")?;
+        writeln!(f, "let goto = goto_{rule_name}(before)?;")?;
+        writeln!(f, "state = goto;")?;
+        writeln!(
+            f,
+            "stack.push(goto, TokenType::NonTerm(NonTerm::{rule_name}), value);"
+        )?;
+
+        Ok(())
+    }
+
+    fn visit_production(
+        &self,
+        ctx: &super::Ctx,
+        f: &mut std::fmt::Formatter,
+        index: usize,
+        entry: &GrammarEntry,
+    ) -> std::fmt::Result {
+        let rule_name = ctx.grammar.pool().get(entry.rule_name());
+        let locations = match self.has_locations() {
+            true => ", locations: &mut Locations",
+            false => "",
+        };
+        writeln!(
+            f,
+            "fn reduce_{index}(stack: &mut Stack{locations}) -> Result<StackValue> {{"
+        )?;
+        for (i, token) in entry.tokens().iter().enumerate().rev() {
             writeln!(f, "let v{i} = {{")?;
             writeln!(f, "match stack.pop()? {{")?;
             write!(f, "(_, ")?;
@@ -407,12 +747,7 @@ impl Visitor for Rust {
                 crate::grammar::Token::Term(id) => {
                     let name = ctx.grammar.pool().get(*id);
                     let has_type = self.terminal_types.contains_key(name);
-                    write!(
-                        f,
-                        "TokenType::Term({}::{name}{}),",
-                        self.token_type,
-                        if has_type { "(_)" } else { "" }
-                    )?;
+                    write!(f, "TokenType::Term(TokenKind::{name}),")?;
 
                     if has_type {
                         write!(f, "StackValue::Term_{name}(v)")?;
@@ -447,27 +782,16 @@ impl Visitor for Rust {
             writeln!(f, "}};")?;
         }
 
-        let code = ctx
-            .grammar
-            .entries()
-            .iter()
-            .find(|x| x.rule_name() == rule && x.tokens() == expansion)
-            .unwrap()
-            .code();
-        if rule_name == "S0" {
-            writeln!(f, "return Ok(v0);")?;
-            return Ok(());
+        // a start rule accepts the input, so the locations are not needed anymore
+        if ctx.grammar.is_start(entry.rule_name()) {
+            writeln!(f, "Ok(StackValue::NonTerm_{rule_name}(v0))")?;
+        } else {
+            self.write_locations(f, entry)?;
+            self.write_bindings(f, entry)?;
+            writeln!(f, "let value = {{ {} }};", self.code(entry))?;
+            writeln!(f, "Ok(StackValue::NonTerm_{rule_name}(value))")?;
         }
-        writeln!(f, "let value = {{ {code} }};",)?;
-
-        writeln!(f, "let &(before, _, _) = stack.peek()?;")?;
-        writeln!(f, "let goto = goto_{rule_name}(before)?;")?;
-        writeln!(f, "state = goto;")?;
-        writeln!(
-            f,
-            "stack.push(goto, TokenType::NonTerm(NonTerm::{rule_name}), StackValue::NonTerm_{rule_name}(value) );"
-        )?;
-
+        writeln!(f, "}}\n")?;
         Ok(())
     }
 
@@ -476,26 +800,29 @@ impl Visitor for Rust {
         ctx: &super::Ctx,
         f: &mut std::fmt::Formatter,
         state: crate::generator::Uid,
-        expected: std::collections::HashSet<crate::grammar::Token>,
+        expected: std::collections::BTreeSet<crate::grammar::Token>,
     ) -> std::fmt::Result {
-        writeln!(
-            f,
-            r#"_ => return Err(Error::UnexpectedToken {{
+        let received = match self.has_locations() {
+            true => {
+                writeln!(f, "_ => {{")?;
+                writeln!(f, "let (received, location) = tokens.next().unzip();")?;
+                writeln!(f, "return Err(Error::UnexpectedToken {{ expected: vec![")?;
+                "received, location"
+            }
+            false => {
+                writeln!(
+                    f,
+                    r#"_ => return Err(Error::UnexpectedToken {{
                 expected: vec!["#
-        )?;
+                )?;
+                "received: tokens.next()"
+            }
+        };
         for tok in expected.iter() {
             match tok {
                 crate::grammar::Token::Term(id) => {
                     let tok_name = ctx.grammar.pool().get(*id);
-                    if self.use_default_for_token {
-                        write!(f, "Some({}::{tok_name}", self.token_type)?;
-                        if self.terminal_types.contains_key(tok_name) {
-                            write!(f, "(Default::default())")?;
-                        }
-                        write!(f, "),")?;
-                    } else {
-                        write!(f, r#" Some("{tok_name}"), "#)?;
-                    }
+                    write!(f, "Some(TokenKind::{tok_name}),")?;
                 }
                 crate::grammar::Token::Eof => write!(f, "None,")?,
                 _ => unreachable!(),
@@ -503,9 +830,12 @@ impl Visitor for Rust {
         }
         writeln!(
             f,
-            "], received: tokens.next(), state_id: {state}, remaining_input: tokens.collect() "
+            "], {received}, state_id: {state}, remaining_input: tokens.collect() "
         )?;
         writeln!(f, "}} )")?;
+        if self.has_locations() {
+            writeln!(f, "}}")?;
+        }
         Ok(())
     }
 
@@ -531,3 +861,90 @@ impl Visitor for Rust {
         Ok(())
     }
 }
+
+/// the terminals used by `grammar`, in a stable order
+pub(super) fn terminals(grammar: &Grammar) -> impl Iterator<Item = Id> + '_ {
+    grammar
+        .entries()
+        .iter()
+        .flat_map(|x| x.tokens())
+        .filter_map(|x| x.term())
+        .sorted()
+        .dedup()
+}
+
+/// The location references in the rust `code` of an action: the offset of each `@` and the word
+/// after it, which is `$`, a position or a name. String and char literals are skipped, a lone `@`
+/// like in the pattern `x @ Some(_)` is no reference.
+pub fn location_refs(code: &str) -> Vec<(usize, &str)> {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    let mut refs = Vec::new();
+    let mut i = 0;
+    while let Some(c) = code[i..].chars().next() {
+        let after_ident = code[..i].chars().next_back().is_some_and(is_ident);
+        if let Some(len) = literal_len(&code[i..], after_ident) {
+            i += len;
+            continue;
+        }
+        if c == '@' {
+            let rest = &code[i + 1..];
+            let len = match rest.starts_with('$') {
+                true => 1,
+                false => rest.find(|x| !is_ident(x)).unwrap_or(rest.len()),
+            };
+            if len > 0 {
+                refs.push((i, &rest[..len]));
+            }
+            i += 1 + len;
+            continue;
+        }
+        i += c.len_utf8();
+    }
+    refs
+}
+
+/// The length of the string or char literal `code` starts with, up to the end of `code` if it is
+/// not closed. `after_ident` is whether an identifier comes right before, where `r"` and `b'` are
+/// no prefixes.
+fn literal_len(code: &str, after_ident: bool) -> Option<usize> {
+    let unprefixed = match after_ident {
+        true => code,
+        false => code.strip_prefix('b').unwrap_or(code),
+    };
+    let prefix = code.len() - unprefixed.len();
+    if let (false, Some(raw)) = (after_ident, unprefixed.strip_prefix('r')) {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        if let Some(body) = raw[hashes..].strip_prefix('"') {
+            let close = format!("\"{}", "#".repeat(hashes));
+            let len = body.find(&close).map_or(body.len(), |x| x + close.len());
+            return Some(prefix + 1 + hashes + 1 + len);
+        }
+    }
+    let quote = unprefixed.chars().next()?;
+    if quote != '"' && quote != '\'' {
+        return None;
+    }
+    let body = &unprefixed[1..];
+    // a `'` that isn't closed right after one char or an escape starts a lifetime
+    if quote == '\'' {
+        let first = body.chars().next()?;
+        let skip = match first {
+            '\\' => 1 + body[1..].chars().next()?.len_utf8(),
+            _ => first.len_utf8(),
+        };
+        if first != '\\' && !body[skip..].starts_with('\'') {
+            return None;
+        }
+    }
+    let mut chars = body.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            c if c == quote => return Some(prefix + 1 + i + 1),
+            _ => {}
+        }
+    }
+    Some(code.len())
+}
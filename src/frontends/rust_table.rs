@@ -0,0 +1,589 @@
+//! A rust backend that encodes the parser as tables instead of code. The actions and gotos are
+//! packed into arrays by row displacement and run by a small driver, and the code of every
+//! production is emitted once as a function. The output grows with the number of productions
+//! rather than with the number of states times their lookaheads.
+
+use std::{cell::RefCell, collections::BTreeMap};
+
+use itertools::Itertools;
+
+use crate::{
+    generator::Uid,
+    grammar::{Grammar, GrammarEntry, Token},
+    string_pool::Id,
+};
+
+use super::{
+    check_types,
+    rust::{terminals, Rust},
+    Ctx, Format, Visitor,
+};
+
+/// what a state does on a token, before it is encoded
+#[derive(Debug, Clone, Copy)]
+enum Entry {
+    Shift(Uid),
+    /// the index of the production in the grammar
+    Reduce(usize),
+}
+
+pub struct RustTable {
+    rust: Rust,
+    /// the actions of each state by column, filled while the table is visited
+    actions: RefCell<BTreeMap<Uid, BTreeMap<usize, Entry>>>,
+    /// the gotos of each rule by state, filled while the table is visited
+    gotos: RefCell<BTreeMap<Id, BTreeMap<Uid, Uid>>>,
+}
+
+impl Format for RustTable {
+    fn format(&self, path: &str) -> anyhow::Result<()> {
+        self.rust.format(path)
+    }
+}
+
+impl RustTable {
+    /// renders the types and the code of `rust` as a table driven parser
+    pub fn new(rust: Rust) -> Self {
+        RustTable {
+            rust,
+            actions: RefCell::new(BTreeMap::new()),
+            gotos: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    /// the parameters of the reduce functions besides the values, and the arguments that pass
+    /// them on
+    fn reduce_params(&self) -> (&'static str, &'static str) {
+        match self.rust.has_locations() {
+            true => (", locations: &mut Locations", ", locations"),
+            false => ("", ""),
+        }
+    }
+}
+
+/// The column of `token` in the action table. Terminals are numbered like the variants of
+/// `TokenKind`, the end of the input comes after all of them.
+fn column(grammar: &Grammar, token: Token) -> usize {
+    match token {
+        Token::Term(id) => terminals(grammar)
+            .position(|x| x == id)
+            .expect("the table only uses terminals of the grammar"),
+        Token::Eof => terminals(grammar).count(),
+        _ => unreachable!("only terminals have actions"),
+    }
+}
+
+/// the rules that have gotos, in the order of their rows in the goto table
+fn rules(grammar: &Grammar) -> impl Iterator<Item = Id> + '_ {
+    grammar
+        .entries()
+        .iter()
+        .map(|x| x.rule_name())
+        .filter(|x| !grammar.is_start(*x))
+        .sorted()
+        .dedup()
+}
+
+/// A sparse table packed into one array. The entry of row `r` for column `c` is at
+/// `base[r] + c`, and only belongs to that row if `check[base[r] + c] == r`.
+#[derive(Debug, PartialEq, Eq)]
+struct Packed {
+    base: Vec<usize>,
+    check: Vec<usize>,
+    value: Vec<usize>,
+}
+
+impl Packed {
+    /// Packs `rows`, given as their non-empty columns and values, by placing each row at the first
+    /// offset where its columns are free. Dense rows are placed first, as they are the hardest to
+    /// fit. Unused slots get a `check` that is no row.
+    fn new(rows: &[Vec<(usize, usize)>]) -> Self {
+        let unused = rows.len();
+        let mut packed = Packed {
+            base: vec![0; rows.len()],
+            check: Vec::new(),
+            value: Vec::new(),
+        };
+        let order = (0..rows.len()).sorted_by_key(|&x| std::cmp::Reverse(rows[x].len()));
+        for row in order {
+            let fits = |base: usize| {
+                rows[row].iter().all(|&(column, _)| {
+                    packed.check.get(base + column).is_none_or(|&x| x == unused)
+                })
+            };
+            let base = (0..)
+                .find(|&x| fits(x))
+                .expect("some offset is past the end");
+            for &(column, value) in &rows[row] {
+                let index = base + column;
+                if index >= packed.check.len() {
+                    packed.check.resize(index + 1, unused);
+                    packed.value.resize(index + 1, 0);
+                }
+                packed.check[index] = row;
+                packed.value[index] = value;
+            }
+            packed.base[row] = base;
+        }
+        packed
+    }
+
+    #[cfg(test)]
+    fn get(&self, row: usize, column: usize) -> Option<usize> {
+        let index = self.base[row] + column;
+        (self.check.get(index) == Some(&row)).then(|| self.value[index])
+    }
+
+    /// writes the arrays as statics called `{name}_BASE`, `{name}_CHECK` and `{name}_VALUE`
+    fn write(&self, f: &mut std::fmt::Formatter, name: &str) -> std::fmt::Result {
+        let max = [&self.base, &self.check, &self.value]
+            .into_iter()
+            .flatten()
+            .copied()
+            .max()
+            .unwrap_or(0);
+        let typ = int_type(max);
+        for (suffix, array) in [
+            ("BASE", &self.base),
+            ("CHECK", &self.check),
+            ("VALUE", &self.value),
+        ] {
+            writeln!(
+                f,
+                "static {name}_{suffix}: [{typ}; {}] = [{}];",
+                array.len(),
+                array.iter().format(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// the smallest unsigned integer type that holds `max`
+fn int_type(max: usize) -> &'static str {
+    if max <= u8::MAX as usize {
+        "u8"
+    } else if max <= u16::MAX as usize {
+        "u16"
+    } else {
+        assert!(max <= u32::MAX as usize, "the parse table is too large");
+        "u32"
+    }
+}
+
+impl Visitor for RustTable {
+    fn check(&self, grammar: &Grammar) -> anyhow::Result<()> {
+        check_types(grammar, self.rust.non_terminal_types())
+    }
+
+    fn before_enter(
+        &self,
+        ctx: &Ctx,
+        f: &mut std::fmt::Formatter,
+        all_states: &[Uid],
+    ) -> std::fmt::Result {
+        let grammar = ctx.grammar;
+        let pool = grammar.pool();
+        let token_type = self.rust.token_type();
+        let input_type = self.rust.input_type();
+        self.rust.write_header(ctx, f)?;
+        self.rust.write_stack_values(ctx, f)?;
+
+        // the driver refers to states by their row in the tables
+        self.rust.write_entry_points(ctx, f, &|_, state| {
+            let row = all_states
+                .binary_search(&state)
+                .expect("start states are states");
+            format!("_parse(tokens, {row})")
+        })?;
+
+        // the parts of the driver that differ when the tokens come with locations
+        let (token, locations, received, fields, shift, reduce) = match self.rust.has_locations() {
+            true => (
+                "&x.0",
+                "let mut locations = Locations::new(tokens.peek().map(|x| &x.1));",
+                "let (received, location) = tokens.next().unzip();",
+                "received, location,",
+                r#"let (value, location) = tokens.next().map_or((StackValue::None, None), |(token, location)| (token_value(token), Some(location)));
+                        values.push(value);
+                        locations.shift(location, tokens.peek().map(|x| &x.1));"#,
+                "reduce(action / 2, &mut values, &mut locations)",
+            ),
+            false => (
+                "x",
+                "",
+                "let received = tokens.next();",
+                "received,",
+                "values.push(tokens.next().map_or(StackValue::None, token_value));",
+                "reduce(action / 2, &mut values)",
+            ),
+        };
+        writeln!(f, "const END: usize = {};", terminals(grammar).count())?;
+        writeln!(
+            f,
+            "static KINDS: [TokenKind; END] = [{}];",
+            terminals(grammar)
+                .map(|x| format!("TokenKind::{}", pool.get(x)))
+                .format(", ")
+        )?;
+        writeln!(
+            f,
+            r#"
+            /// the entry of a packed table in `row` and `column`
+            fn lookup<T: Copy + Into<u32>>(base: &[T], check: &[T], value: &[T], row: usize, column: usize) -> Option<usize> {{
+                let index = base[row].into() as usize + column;
+                match check.get(index) {{
+                    Some(&x) if x.into() as usize == row => Some(value[index].into() as usize),
+                    _ => None,
+                }}
+            }}
+
+            fn action(state: usize, column: usize) -> Option<usize> {{
+                lookup(&ACTION_BASE, &ACTION_CHECK, &ACTION_VALUE, state, column)
+            }}
+
+            fn _parse<I>(tokens: I, start: usize) -> Result<StackValue> where I: Iterator<Item = {input_type}>, {{
+                let mut tokens = tokens.peekable();
+                {locations}
+                let mut states = vec![start];
+                let mut values = Vec::new();
+                loop {{
+                    let state = *states.last().ok_or_else(|| Error::msg("stack was empty. this is probably a bug"))?;
+                    let column = tokens.peek().map_or(END, |x| token_column({token}));
+                    let Some(action) = action(state, column) else {{
+                        let expected = (0..=END)
+                            .filter(|&x| action(state, x).is_some())
+                            .map(|x| KINDS.get(x).copied())
+                            .collect();
+                        {received}
+                        return Err(Error::UnexpectedToken {{
+                            expected,
+                            {fields}
+                            state_id: STATE_IDS[state] as usize,
+                            remaining_input: tokens.collect(),
+                        }});
+                    }};
+
+                    // even actions shift to the state `action / 2`, odd ones reduce the production `action / 2`
+                    if action % 2 == 0 {{
+                        {shift}
+                        states.push(action / 2);
+                        continue;
+                    }}
+                    let (len, rule) = PRODUCTIONS[action / 2];
+                    let value = {reduce}?;
+                    states.truncate(states.len() - len);
+                    let Some(rule) = rule else {{
+                        return Ok(value);
+                    }};
+                    let &state = states.last().ok_or_else(|| Error::msg("stack was empty. this is probably a bug"))?;
+                    let goto = lookup(&GOTO_BASE, &GOTO_CHECK, &GOTO_VALUE, rule, state)
+                        .ok_or_else(|| Error::msg("no goto for the reduced rule. this is probably a bug"))?;
+                    states.push(goto);
+                    values.push(value);
+                }}
+            }}
+            "#
+        )?;
+
+        writeln!(
+            f,
+            "/// the column of `token` in the action table. tokens that are no terminals of the grammar get a column without actions"
+        )?;
+        writeln!(f, "#[allow(unreachable_patterns)]")?;
+        writeln!(f, "fn token_column(token: &{token_type}) -> usize {{")?;
+        writeln!(f, "match token {{")?;
+        for (i, term) in terminals(grammar).enumerate() {
+            let name = pool.get(term);
+            let data = match self.rust.terminal_types().contains_key(name) {
+                true => "(_)",
+                false => "",
+            };
+            writeln!(f, "{token_type}::{name}{data} => {i},")?;
+        }
+        writeln!(f, "_ => END + 1,")?;
+        writeln!(f, "}} }}\n")?;
+
+        writeln!(f, "#[allow(unreachable_patterns)]")?;
+        writeln!(f, "fn token_value(token: {token_type}) -> StackValue {{")?;
+        writeln!(f, "match token {{")?;
+        for term in terminals(grammar) {
+            let name = pool.get(term);
+            if self.rust.terminal_types().contains_key(name) {
+                writeln!(f, "{token_type}::{name}(v) => StackValue::Term_{name}(v),")?;
+            }
+        }
+        writeln!(f, "_ => StackValue::None,")?;
+        writeln!(f, "}} }}\n")?;
+
+        let (params, args) = self.reduce_params();
+        writeln!(
+            f,
+            "fn reduce(production: usize, values: &mut Vec<StackValue>{params}) -> Result<StackValue> {{"
+        )?;
+        writeln!(f, "match production {{")?;
+        for i in 0..grammar.entries().len() {
+            writeln!(f, "{i} => reduce_{i}(values{args}),")?;
+        }
+        writeln!(
+            f,
+            r#"_ => Err(Error::msg("unknown production. this is probably a bug")),"#
+        )?;
+        writeln!(f, "}} }}\n")?;
+
+        Ok(())
+    }
+
+    fn after_leave(
+        &self,
+        ctx: &Ctx,
+        f: &mut std::fmt::Formatter,
+        all_states: &[Uid],
+    ) -> std::fmt::Result {
+        let grammar = ctx.grammar;
+        let row = |state: &Uid| all_states.binary_search(state).expect("states are visited");
+
+        let actions = self.actions.borrow();
+        let rows = all_states
+            .iter()
+            .map(|state| {
+                let actions = actions.get(state).into_iter().flatten();
+                let encoded = actions.map(|(&column, &entry)| match entry {
+                    Entry::Shift(next) => (column, 2 * row(&next)),
+                    Entry::Reduce(production) => (column, 2 * production + 1),
+                });
+                encoded.collect()
+            })
+            .collect_vec();
+        Packed::new(&rows).write(f, "ACTION")?;
+
+        let gotos = self.gotos.borrow();
+        let rows = rules(grammar)
+            .map(|rule| {
+                let gotos = gotos.get(&rule).into_iter().flatten();
+                gotos.map(|(from, to)| (row(from), row(to))).collect()
+            })
+            .collect_vec();
+        Packed::new(&rows).write(f, "GOTO")?;
+
+        let rule_rows = rules(grammar).collect_vec();
+        let productions = grammar.entries().iter().map(|entry| {
+            let rule = rule_rows.iter().position(|&x| x == entry.rule_name());
+            let rule = rule.map_or("None".to_owned(), |x| format!("Some({x})"));
+            format!("({}, {rule})", entry.tokens().len())
+        });
+        writeln!(
+            f,
+            "/// the number of symbols of each production and the row of its rule in the goto table. \
+             reducing a start rule accepts the input"
+        )?;
+        writeln!(
+            f,
+            "static PRODUCTIONS: [(usize, Option<usize>); {}] = [{}];",
+            grammar.entries().len(),
+            productions.format(", ")
+        )?;
+        writeln!(
+            f,
+            "static STATE_IDS: [{}; {}] = [{}];",
+            int_type(all_states.last().copied().unwrap_or(0)),
+            all_states.len(),
+            all_states.iter().format(", ")
+        )?;
+
+        writeln!(f, "}}")?;
+        writeln!(f, "#[allow(unused_imports)]")?;
+        writeln!(f, "pub use parser::*;")?;
+        Ok(())
+    }
+
+    fn begin_parse_loop(&self, _ctx: &Ctx, _f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        Ok(())
+    }
+
+    fn end_parse_loop(&self, _ctx: &Ctx, _f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        Ok(())
+    }
+
+    fn enter_state(
+        &self,
+        _ctx: &Ctx,
+        _f: &mut std::fmt::Formatter,
+        _state: Uid,
+    ) -> std::fmt::Result {
+        Ok(())
+    }
+
+    fn leave_state(
+        &self,
+        _ctx: &Ctx,
+        _f: &mut std::fmt::Formatter,
+        _state: Uid,
+    ) -> std::fmt::Result {
+        Ok(())
+    }
+
+    fn enter_match(
+        &self,
+        _ctx: &Ctx,
+        _f: &mut std::fmt::Formatter,
+        _state: Uid,
+        _token: Token,
+    ) -> std::fmt::Result {
+        Ok(())
+    }
+
+    fn leave_match(
+        &self,
+        _ctx: &Ctx,
+        _f: &mut std::fmt::Formatter,
+        _state: Uid,
+        _token: Token,
+    ) -> std::fmt::Result {
+        Ok(())
+    }
+
+    fn visit_shift(
+        &self,
+        ctx: &Ctx,
+        _f: &mut std::fmt::Formatter,
+        state: Uid,
+        token: Token,
+        next_state: Uid,
+    ) -> std::fmt::Result {
+        let column = column(ctx.grammar, token);
+        let mut actions = self.actions.borrow_mut();
+        actions
+            .entry(state)
+            .or_default()
+            .insert(column, Entry::Shift(next_state));
+        Ok(())
+    }
+
+    fn visit_reduce(
+        &self,
+        ctx: &Ctx,
+        _f: &mut std::fmt::Formatter,
+        state: Uid,
+        token: Token,
+        rule: Id,
+        expansion: &[Token],
+    ) -> std::fmt::Result {
+        let column = column(ctx.grammar, token);
+        let production = ctx
+            .grammar
+            .entry_index(rule, expansion)
+            .expect("reductions refer to productions of the grammar");
+        let mut actions = self.actions.borrow_mut();
+        actions
+            .entry(state)
+            .or_default()
+            .insert(column, Entry::Reduce(production));
+        Ok(())
+    }
+
+    fn visit_production(
+        &self,
+        ctx: &Ctx,
+        f: &mut std::fmt::Formatter,
+        index: usize,
+        entry: &GrammarEntry,
+    ) -> std::fmt::Result {
+        let grammar = ctx.grammar;
+        let pool = grammar.pool();
+        let rule_name = pool.get(entry.rule_name());
+        let (params, _) = self.reduce_params();
+        writeln!(
+            f,
+            "fn reduce_{index}(values: &mut Vec<StackValue>{params}) -> Result<StackValue> {{"
+        )?;
+        for (i, token) in entry.tokens().iter().enumerate().rev() {
+            let (pattern, value) = match *token {
+                Token::Term(id) if self.rust.terminal_types().contains_key(pool.get(id)) => {
+                    (format!("StackValue::Term_{}(v)", pool.get(id)), "v")
+                }
+                Token::Term(_) | Token::Eof => ("StackValue::None".to_owned(), "()"),
+                Token::NonTerm(id) => (format!("StackValue::NonTerm_{}(v)", pool.get(id)), "v"),
+                Token::Empty => unreachable!(),
+            };
+            writeln!(f, "let v{i} = match values.pop() {{")?;
+            writeln!(f, "Some({pattern}) => {value},")?;
+            writeln!(
+                f,
+                r#"_ => return Err(Error::msg("expected token {} to be on the stack")),"#,
+                token.display(pool)
+            )?;
+            writeln!(f, "}};")?;
+        }
+        // a start rule accepts the input, so the locations are not needed anymore
+        if grammar.is_start(entry.rule_name()) {
+            writeln!(f, "Ok(StackValue::NonTerm_{rule_name}(v0))")?;
+        } else {
+            self.rust.write_locations(f, entry)?;
+            self.rust.write_bindings(f, entry)?;
+            writeln!(f, "let value = {{ {} }};", self.rust.code(entry))?;
+            writeln!(f, "Ok(StackValue::NonTerm_{rule_name}(value))")?;
+        }
+        writeln!(f, "}}\n")?;
+        Ok(())
+    }
+
+    fn matching_error(
+        &self,
+        _ctx: &Ctx,
+        _f: &mut std::fmt::Formatter,
+        _state: Uid,
+        _expected: std::collections::BTreeSet<Token>,
+    ) -> std::fmt::Result {
+        // the driver finds the expected tokens in the table
+        Ok(())
+    }
+
+    fn visit_goto(
+        &self,
+        _ctx: &Ctx,
+        _f: &mut std::fmt::Formatter,
+        symbol: Id,
+        gotos: &mut dyn Iterator<Item = (Uid, Uid)>,
+    ) -> std::fmt::Result {
+        self.gotos.borrow_mut().insert(symbol, gotos.collect());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packed_rows_keep_their_entries() {
+        let rows = vec![
+            vec![(0, 1), (2, 2)],
+            vec![(1, 3)],
+            vec![],
+            vec![(0, 4), (1, 5), (3, 6)],
+            vec![(2, 7)],
+        ];
+        let packed = Packed::new(&rows);
+        for (row, entries) in rows.iter().enumerate() {
+            for column in 0..4 {
+                let expected = entries.iter().find(|x| x.0 == column).map(|x| x.1);
+                assert_eq!(
+                    packed.get(row, column),
+                    expected,
+                    "row {row}, column {column}"
+                );
+            }
+        }
+        // the rows interleave instead of taking 4 slots each
+        assert!(packed.value.len() < 4 * 3, "{packed:?}");
+    }
+
+    #[test]
+    fn smallest_int_types() {
+        assert_eq!(int_type(0), "u8");
+        assert_eq!(int_type(255), "u8");
+        assert_eq!(int_type(256), "u16");
+        assert_eq!(int_type(70000), "u32");
+    }
+}
@@ -1,5 +1,5 @@
 use std::{
-    collections::{BTreeSet, HashMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
     fmt::Display,
 };
 
@@ -7,13 +7,12 @@ use itertools::Itertools;
 use tracing::info;
 
 use crate::{
+    diagnostics::Diagnostic,
     grammar::{Grammar, Token},
     string_pool::{Id, Pool},
+    table::Action,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct Production(Id, Vec<Token>);
-
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct State {
     pub(crate) rule: Id,
@@ -67,132 +66,443 @@ impl Display for StateDisplay<'_> {
 
 pub type Uid = usize;
 
-#[derive(Debug, Clone)]
-pub struct Graph(pub(crate) HashMap<Uid, (HashSet<State>, HashMap<Uid, Token>)>);
+/// an LR(1) item without its lookahead
+type Core<'a> = (Id, &'a [Token], &'a [Token]);
 
-struct GenUid(usize);
-impl GenUid {
-    fn next(&mut self) -> usize {
-        self.0 += 1;
-        self.0
-    }
+/// the productions that are reduced in a state, by lookahead
+type Reductions<'a> = HashMap<Token, BTreeSet<(Id, &'a [Token])>>;
+
+/// A reduce/reduce conflict that only exists because states with the same core were merged. None
+/// of the `merged` states has this conflict on its own.
+#[derive(Debug, Clone)]
+pub struct MergeConflict {
+    /// the id of the merged state
+    pub state: Uid,
+    pub token: Token,
+    /// the ids the states had in the canonical graph
+    pub merged: Vec<Uid>,
+    pub reductions: Vec<Action>,
 }
 
-fn closure(grammar: &Grammar, mut states: HashSet<State>) -> HashSet<State> {
-    loop {
-        let mut new_state = states.clone();
-        let mut num_added = 0;
-        for state in &states {
-            let [c, delta @ ..] = state.after.as_slice() else {
+impl MergeConflict {
+    /// a warning labelling the productions that are reduced on the same token
+    pub fn diagnostic(&self, grammar: &Grammar) -> Diagnostic {
+        let pool = grammar.pool();
+        let mut diagnostic = Diagnostic::warning(format!(
+            "merging states {} into state {} introduces a reduce/reduce conflict on {}",
+            self.merged.iter().format(", "),
+            self.state,
+            self.token.display(pool),
+        ));
+        for (i, reduction) in self.reductions.iter().enumerate() {
+            let Action::Reduce(rule, expansion) = reduction else {
                 continue;
             };
+            let span = grammar
+                .entry_index(*rule, expansion)
+                .and_then(|x| grammar.entries()[x].span());
+            if let Some(span) = span {
+                let message = format!("reduced on {}", self.token.display(pool));
+                diagnostic = match i {
+                    0 => diagnostic.label(span, message),
+                    _ => diagnostic.secondary(span, message),
+                };
+            }
+            diagnostic =
+                diagnostic.note(format!("reduces {}", reduction.display(pool, self.token)));
+        }
+        diagnostic.note("this conflict does not exist with --algorithm lr1")
+    }
+}
 
-            let mut lookahead = grammar.first(delta);
-            let Token::NonTerm(c) = c else {
-                continue;
-            };
+#[derive(Debug, Clone)]
+pub struct Graph(pub(crate) BTreeMap<Uid, (BTreeSet<State>, BTreeMap<Uid, Token>)>);
+
+/// An LR(0) item, the `dot`-th position in the `production`-th entry of the grammar. Cheaper to
+/// hash and compare than [`State`], which is only built once a state of the graph is complete.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Item {
+    production: usize,
+    dot: usize,
+}
 
-            if lookahead.contains(&Token::Empty) {
-                lookahead.remove(&Token::Empty);
-                for &tok in &state.lookahead {
-                    lookahead.insert(tok);
-                }
-            }
+/// the items a state was reached with, sorted by item. Identifies the state, as the closure is
+/// determined by it
+type Kernel = Vec<(Item, BTreeSet<Token>)>;
 
-            let added = grammar.initial(*c).into_iter().map(move |x| State {
-                lookahead: lookahead.clone(),
-                ..x
-            });
-            for i in added {
-                let could_add = new_state.insert(i);
-                num_added += if could_add { 1 } else { 0 };
-            }
-        }
+fn closure(grammar: &Grammar, kernel: &Kernel) -> HashMap<Item, BTreeSet<Token>> {
+    let mut items: HashMap<Item, BTreeSet<Token>> = kernel.iter().cloned().collect();
+    let mut work: Vec<Item> = kernel.iter().map(|(item, _)| *item).collect();
+
+    while let Some(item) = work.pop() {
+        let tokens = grammar.entries()[item.production].tokens();
+        let Some(&Token::NonTerm(c)) = tokens.get(item.dot) else {
+            continue;
+        };
 
-        if num_added == 0 {
-            return new_state;
+        let mut lookahead = grammar.first(&tokens[item.dot + 1..]);
+        if lookahead.remove(&Token::Empty) {
+            lookahead.extend(&items[&item]);
         }
 
-        states = new_state;
+        for &production in grammar.entry_indices(c) {
+            let next = Item { production, dot: 0 };
+            // only revisit items whose lookahead grew, so this terminates
+            let (known, added) = match items.get_mut(&next) {
+                Some(known) => {
+                    let len = known.len();
+                    known.extend(&lookahead);
+                    (true, known.len() != len)
+                }
+                None => {
+                    items.insert(next, lookahead.clone());
+                    (false, true)
+                }
+            };
+            if !known || added {
+                work.push(next);
+            }
+        }
     }
+
+    items
 }
 
-fn out_edges<'a, 'b>(states: &'b HashSet<State>) -> impl IntoIterator<Item = Token> + 'a
-where
-    'b: 'a,
-{
-    states
-        .iter()
-        .filter_map(|x| x.after.split_first().map(|(&x, _)| x))
-        .collect::<HashSet<_>>()
+/// the kernels of the states reachable from a closed set of items, by the token on the edge
+fn successors(
+    grammar: &Grammar,
+    items: &HashMap<Item, BTreeSet<Token>>,
+) -> BTreeMap<Token, Kernel> {
+    let mut successors: BTreeMap<Token, BTreeMap<Item, BTreeSet<Token>>> = BTreeMap::new();
+    for (item, lookahead) in items {
+        let Some(&edge) = grammar.entries()[item.production].tokens().get(item.dot) else {
+            continue;
+        };
+        let advanced = Item {
+            dot: item.dot + 1,
+            ..*item
+        };
+        successors
+            .entry(edge)
+            .or_default()
+            .entry(advanced)
+            .or_default()
+            .extend(lookahead);
+    }
+
+    successors
+        .into_iter()
+        .map(|(edge, kernel)| (edge, kernel.into_iter().collect()))
+        .collect()
 }
 
-fn advance_states<'a>(edge: Token, states: impl Iterator<Item = &'a State>) -> HashSet<State> {
-    states
-        .filter_map(|x| {
-            let (hd, tl) = x.after.split_first()?;
-            if hd != &edge {
-                return None;
+fn to_states(grammar: &Grammar, items: HashMap<Item, BTreeSet<Token>>) -> BTreeSet<State> {
+    items
+        .into_iter()
+        .map(|(item, lookahead)| {
+            let entry = &grammar.entries()[item.production];
+            let (before, after) = entry.tokens().split_at(item.dot);
+            State {
+                rule: entry.rule_name(),
+                before: before.to_vec(),
+                after: after.to_vec(),
+                lookahead,
             }
-
-            let mut x = x.to_owned();
-            x.after = tl.to_owned();
-            x.before.push(edge);
-            Some(x)
         })
         .collect()
 }
 
 impl Graph {
+    /// Constructs the canonical LR(1) graph starting from the start rules of `grammar`. The state
+    /// of the `i`-th start rule is `i + 1`, the other states are numbered in the order they are
+    /// discovered. States are identified by their kernel, so each one is only closed once.
     #[tracing::instrument(skip(grammar))]
-    pub fn make(grammar: &Grammar, states: HashSet<State>) -> Graph {
+    pub fn make(grammar: &Grammar) -> Graph {
         info!("constructing graph");
-        fn inner(
-            g: &mut Graph,
-            grammar: &Grammar,
-            uid: &mut GenUid,
-            states: HashSet<State>,
-        ) -> usize {
-            let states = closure(grammar, states);
-            // state already exists in the graph, just go to the state
-            if let Some((&key, _)) = g.0.iter().find(|(_, (x, _))| *x == states) {
-                return key;
-            }
-
-            let id = uid.next();
-
-            g.0.insert(id, (states.clone(), HashMap::new()));
-            for edge in out_edges(&states) {
-                let advanced = advance_states(edge, states.iter());
-                let next = inner(g, grammar, uid, advanced);
-                let next_entry = g.0.entry(id);
-                // println!(
-                //     "adding {next} to {id}. reason: {}",
-                //     edge.display(grammar.pool())
-                // );
-                next_entry
-                    .and_modify(|x| {
-                        x.1.insert(next, edge);
+        let starts: Vec<Kernel> = grammar
+            .starts()
+            .iter()
+            .map(|&start| {
+                grammar
+                    .initial(start)
+                    .into_iter()
+                    .map(|x| {
+                        let production = grammar
+                            .entry_index(x.rule, &x.after)
+                            .expect("initial state refers to a production of the grammar");
+                        (Item { production, dot: 0 }, x.lookahead)
                     })
-                    .or_insert_with(|| (states.clone(), HashMap::new()));
+                    .sorted()
+                    .collect()
+            })
+            .collect();
+
+        let mut graph = Graph(BTreeMap::new());
+        let mut ids: HashMap<Kernel, Uid> = HashMap::new();
+        let mut queue = VecDeque::new();
+        for (i, start) in starts.into_iter().enumerate() {
+            ids.insert(start.clone(), i + 1);
+            queue.push_back((i + 1, start));
+        }
+        while let Some((id, kernel)) = queue.pop_front() {
+            let items = closure(grammar, &kernel);
+            let mut edges = BTreeMap::new();
+            for (edge, next) in successors(grammar, &items) {
+                let next_id = ids.len() + 1;
+                let next_id = *ids.entry(next.clone()).or_insert_with(|| {
+                    queue.push_back((next_id, next));
+                    next_id
+                });
+                edges.insert(next_id, edge);
             }
+            graph.0.insert(id, (to_states(grammar, items), edges));
+        }
+
+        info!("graph has {} states", graph.0.len());
+        graph
+    }
 
-            id
+    /// Turns the canonical LR(1) graph into an LALR(1) graph by merging all states that have the
+    /// same LR(0) core. Also reports the reduce/reduce conflicts that are introduced by merging.
+    #[tracing::instrument(skip(self))]
+    pub fn lalr(&self) -> (Graph, Vec<MergeConflict>) {
+        let mut groups: HashMap<BTreeSet<Core>, Vec<Uid>> = HashMap::new();
+        for (&id, (states, _)) in &self.0 {
+            groups.entry(core(states)).or_default().push(id);
         }
+        let groups: Vec<_> = groups.into_values().collect();
+
+        let (graph, ids) = self.merge(&groups);
+        let conflicts = self.merge_conflicts(&groups, &ids);
+        info!("lalr merged {} states into {}", self.0.len(), graph.0.len());
+        (graph, conflicts)
+    }
 
-        let mut graph = Graph(HashMap::new());
-        let mut gen_uid = GenUid(0);
-        inner(&mut graph, grammar, &mut gen_uid, states);
+    /// Merges states with the same core, like [`Graph::lalr`], but only where that does not
+    /// introduce reduce/reduce conflicts. This gives tables of about the size of LALR(1) that
+    /// accept exactly the grammars canonical LR(1) accepts.
+    #[tracing::instrument(skip(self))]
+    pub fn minimal(&self) -> Graph {
+        let mut groups: HashMap<BTreeSet<Core>, Vec<Uid>> = HashMap::new();
+        for (&id, (states, _)) in &self.0 {
+            groups.entry(core(states)).or_default().push(id);
+        }
+        let mut partition: Vec<Vec<Uid>> = groups.into_values().collect();
+
+        // splitting a group can make the groups of its predecessors transition into different
+        // groups, which splits those as well. repeat until nothing changes
+        loop {
+            let compatible: Vec<_> = partition
+                .iter()
+                .flat_map(|group| self.split_compatible(group))
+                .collect();
+
+            let group_of: HashMap<Uid, usize> = compatible
+                .iter()
+                .enumerate()
+                .flat_map(|(i, group)| group.iter().map(move |&id| (id, i)))
+                .collect();
+            let mut refined = Vec::new();
+            for group in compatible {
+                let mut by_targets: BTreeMap<Vec<(Token, usize)>, Vec<Uid>> = BTreeMap::new();
+                for id in group {
+                    let targets = self.0[&id]
+                        .1
+                        .iter()
+                        .map(|(to, &token)| (token, group_of[to]))
+                        .sorted()
+                        .collect();
+                    by_targets.entry(targets).or_default().push(id);
+                }
+                refined.extend(by_targets.into_values());
+            }
 
-        info!("graph has {} states", graph.0.len());
+            // groups are only ever split, so the same number of groups means nothing changed
+            let done = refined.len() == partition.len();
+            partition = refined;
+            if done {
+                break;
+            }
+        }
+
+        let (graph, _) = self.merge(&partition);
+        info!(
+            "minimal lr(1) merged {} states into {}",
+            self.0.len(),
+            graph.0.len()
+        );
         graph
     }
 
+    /// Splits a group of states with the same core into groups that can be merged without new
+    /// reduce/reduce conflicts. Two states are compatible if, for every lookahead, the
+    /// productions one of them reduces include all productions the other one reduces.
+    fn split_compatible(&self, group: &[Uid]) -> Vec<Vec<Uid>> {
+        let mut subgroups: Vec<(Vec<Uid>, Reductions)> = Vec::new();
+        for &id in group.iter().sorted() {
+            let own = reductions(&self.0[&id].0);
+            let compatible = subgroups.iter_mut().find(|(_, merged)| {
+                own.iter().all(|(token, own)| {
+                    merged
+                        .get(token)
+                        .is_none_or(|merged| merged.is_superset(own) || merged.is_subset(own))
+                })
+            });
+            match compatible {
+                Some((members, merged)) => {
+                    members.push(id);
+                    for (token, own) in own {
+                        merged.entry(token).or_default().extend(own);
+                    }
+                }
+                None => subgroups.push((vec![id], own)),
+            }
+        }
+        subgroups.into_iter().map(|x| x.0).collect()
+    }
+
+    /// Merges every group of states with the same core into a single state. The merged states are
+    /// numbered like [`Graph::make`] numbers them, the returned map takes the ids of `self` to
+    /// those of the merged graph.
+    fn merge(&self, groups: &[Vec<Uid>]) -> (Graph, HashMap<Uid, Uid>) {
+        let representative: HashMap<Uid, Uid> = groups
+            .iter()
+            .flat_map(|group| {
+                let min = *group.iter().min().expect("groups are never empty");
+                group.iter().map(move |&x| (x, min))
+            })
+            .collect();
+
+        let mut graph = BTreeMap::new();
+        for group in groups {
+            let mut lookaheads: BTreeMap<Core, BTreeSet<Token>> = BTreeMap::new();
+            let mut edges = BTreeMap::new();
+            for id in group {
+                let (states, neighbors) = &self.0[id];
+                for state in states {
+                    lookaheads
+                        .entry((state.rule, &state.before, &state.after))
+                        .or_default()
+                        .extend(state.lookahead.iter().copied());
+                }
+                for (to, &token) in neighbors {
+                    edges.insert(representative[to], token);
+                }
+            }
+
+            let states = lookaheads
+                .into_iter()
+                .map(|((rule, before, after), lookahead)| State {
+                    rule,
+                    before: before.to_vec(),
+                    after: after.to_vec(),
+                    lookahead,
+                })
+                .collect();
+            graph.insert(representative[&group[0]], (states, edges));
+        }
+
+        let (graph, renamed) = Graph(graph).renumber();
+        let ids = representative
+            .into_iter()
+            .map(|(id, min)| (id, renamed[&min]))
+            .collect();
+        (graph, ids)
+    }
+
+    /// Numbers the states in breadth first order from the states of the start rules, visiting the
+    /// successors of a state ordered by the token on the edge. The states of the start rules keep
+    /// their ids. Returns the map from old to new ids.
+    fn renumber(self) -> (Graph, HashMap<Uid, Uid>) {
+        // no edge leads back to the beginning of a start rule, so only those states have none
+        let targets: HashSet<Uid> = self.0.values().flat_map(|x| x.1.keys()).copied().collect();
+        let starts: Vec<Uid> = self
+            .0
+            .keys()
+            .filter(|x| !targets.contains(x))
+            .copied()
+            .collect();
+        let mut ids: HashMap<Uid, Uid> = starts
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| (x, i + 1))
+            .collect();
+        let mut queue = VecDeque::from(starts);
+        while let Some(id) = queue.pop_front() {
+            for (&to, _) in self.0[&id].1.iter().sorted_by_key(|x| x.1) {
+                let next = ids.len() + 1;
+                ids.entry(to).or_insert_with(|| {
+                    queue.push_back(to);
+                    next
+                });
+            }
+        }
+
+        let graph = self
+            .0
+            .into_iter()
+            .filter_map(|(id, (states, edges))| {
+                let edges = edges.into_iter().map(|(to, x)| (ids[&to], x)).collect();
+                Some((*ids.get(&id)?, (states, edges)))
+            })
+            .collect();
+        (Graph(graph), ids)
+    }
+
+    /// the reduce/reduce conflicts that merging `groups` would introduce
+    fn merge_conflicts(&self, groups: &[Vec<Uid>], ids: &HashMap<Uid, Uid>) -> Vec<MergeConflict> {
+        let mut conflicts = Vec::new();
+        for group in groups.iter().filter(|x| x.len() > 1) {
+            let merged = reductions(group.iter().flat_map(|id| &self.0[id].0));
+            let separate: Vec<_> = group.iter().map(|id| reductions(&self.0[id].0)).collect();
+            for (token, reduce) in merged {
+                let existed = separate
+                    .iter()
+                    .any(|x| x.get(&token).is_some_and(|x| x.len() > 1));
+                if reduce.len() < 2 || existed {
+                    continue;
+                }
+                conflicts.push(MergeConflict {
+                    state: ids[&group[0]],
+                    token,
+                    merged: group.iter().copied().sorted().collect(),
+                    reductions: reduce
+                        .into_iter()
+                        .map(|(rule, expansion)| Action::Reduce(rule, expansion.to_vec()))
+                        .collect(),
+                });
+            }
+        }
+        conflicts.sort_by_key(|x| (x.state, x.token));
+        conflicts
+    }
+
     pub fn print<'a>(&'a self, pool: &'a Pool) -> GraphPrinter<'a> {
         GraphPrinter { graph: self, pool }
     }
 }
 
+fn core(states: &BTreeSet<State>) -> BTreeSet<Core<'_>> {
+    states
+        .iter()
+        .map(|x| (x.rule, x.before.as_slice(), x.after.as_slice()))
+        .collect()
+}
+
+fn reductions<'a>(states: impl IntoIterator<Item = &'a State>) -> Reductions<'a> {
+    let mut reductions: Reductions = HashMap::new();
+    for state in states.into_iter().filter(|x| x.after.is_empty()) {
+        let production = (state.rule, state.before.as_slice());
+        if state.lookahead.is_empty() {
+            reductions.entry(Token::Eof).or_default().insert(production);
+        }
+        for &token in &state.lookahead {
+            reductions.entry(token).or_default().insert(production);
+        }
+    }
+    reductions
+}
+
 pub struct GraphPrinter<'a> {
     graph: &'a Graph,
     pool: &'a Pool,
@@ -229,3 +539,102 @@ impl Display for GraphPrinter<'_> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::Table;
+
+    #[test]
+    fn lalr_merges_states() {
+        let g = crate::grammar!(
+            E:
+            E => N "E" T "plus" N "T" @ "";
+            E => N "T" @ "";
+            T => T "lparen" N "E" T "rparen" @ "";
+            T => T "x" @ "";
+        );
+        let lr1 = Graph::make(&g);
+        let (lalr, conflicts) = lr1.lalr();
+        assert!(conflicts.is_empty());
+        assert!(lalr.0.len() < lr1.0.len());
+        assert!(Table::from_graph(&lalr, &g).is_ok());
+    }
+
+    #[test]
+    fn lalr_reports_merge_conflicts() {
+        let g = crate::grammar!(
+            S:
+            S => T "a" N "A" T "d" @ "";
+            S => T "b" N "B" T "d" @ "";
+            S => T "a" N "B" T "e" @ "";
+            S => T "b" N "A" T "e" @ "";
+            A => T "c" @ "";
+            B => T "c" @ "";
+        );
+        let lr1 = Graph::make(&g);
+        assert!(Table::from_graph(&lr1, &g).is_ok());
+
+        let (lalr, conflicts) = lr1.lalr();
+        let d = Token::Term(g.pool().get_reverse("d").unwrap());
+        let e = Token::Term(g.pool().get_reverse("e").unwrap());
+        assert_eq!(conflicts.len(), 2);
+        assert_eq!(
+            conflicts.iter().map(|x| x.token).collect::<BTreeSet<_>>(),
+            BTreeSet::from([d, e])
+        );
+        assert!(conflicts.iter().all(|x| x.merged.len() == 2));
+        assert_eq!(Table::from_graph(&lalr, &g).unwrap_err().0.len(), 2);
+
+        // only the two states that cause the conflicts stay separate
+        let minimal = lr1.minimal();
+        assert_eq!(minimal.0.len(), lalr.0.len() + 1);
+        assert!(Table::from_graph(&minimal, &g).is_ok());
+    }
+
+    #[test]
+    fn minimal_matches_lalr_without_conflicts() {
+        let g = crate::grammar!(
+            E:
+            E => N "E" T "plus" N "T" @ "";
+            E => N "T" @ "";
+            T => T "lparen" N "E" T "rparen" @ "";
+            T => T "x" @ "";
+        );
+        let lr1 = Graph::make(&g);
+        assert_eq!(lr1.minimal().0.len(), lr1.lalr().0 .0.len());
+    }
+
+    #[test]
+    fn each_start_rule_has_its_own_state() {
+        // Prog -> Expr semi, Expr -> Expr plus x | x
+        let s = String::from;
+        let mut builder = Grammar::builder();
+        let prod = builder
+            .prod_builder()
+            .non_term(s("Expr"))
+            .term(s("semi"))
+            .finish();
+        builder = builder.production(s("Prog"), prod, s(""));
+        let prod = builder
+            .prod_builder()
+            .non_term(s("Expr"))
+            .term(s("plus"))
+            .term(s("x"))
+            .finish();
+        builder = builder.production(s("Expr"), prod, s(""));
+        let prod = builder.prod_builder().term(s("x")).finish();
+        builder = builder.production(s("Expr"), prod, s(""));
+        let g = builder.finish_with_entries(vec!["Prog".to_owned(), "Expr".to_owned()]);
+
+        let lr1 = Graph::make(&g);
+        for graph in [lr1.clone(), lr1.lalr().0, lr1.minimal()] {
+            for (i, &start) in g.starts().iter().enumerate() {
+                let (states, _) = &graph.0[&(i + 1)];
+                assert!(states
+                    .iter()
+                    .any(|x| x.rule == start && x.before.is_empty()));
+            }
+        }
+    }
+}
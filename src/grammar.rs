@@ -1,12 +1,16 @@
-use std::{collections::BTreeSet, fmt::Display};
-
-use itertools::{Either, Itertools};
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    fmt::Display,
+};
 
 use crate::{
     generator::State,
+    source::Span,
     string_pool::{Id, Pool},
 };
 
+mod analysis;
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Token {
     Term(Id),
@@ -52,7 +56,6 @@ impl Token {
         }
     }
 
-    #[allow(dead_code)]
     pub fn non_term(self) -> Option<Id> {
         match self {
             Self::NonTerm(i) => Some(i),
@@ -73,11 +76,50 @@ impl Token {
     }
 }
 
+/// Associativity of a precedence level, as declared by `%left`, `%right` or `%nonassoc`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Assoc {
+    Left,
+    Right,
+    Nonassoc,
+}
+
+/// The precedence of a terminal. Higher levels bind tighter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Precedence {
+    pub level: usize,
+    pub assoc: Assoc,
+}
+
+/// How a shift/reduce conflict was decided by the precedence declarations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+    Shift,
+    Reduce,
+    /// the token is non-associative, so neither action is taken and the parser reports an error
+    Error,
+}
+
+/// What to do when two productions can be reduced on the same lookahead
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReduceReduce {
+    /// report the conflict
+    #[default]
+    Error,
+    /// reduce the production that was declared first in the grammar
+    Earlier,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct GrammarEntry {
     rule_name: Id,
     tokens: Vec<Token>,
+    /// the names the code may use for each of the tokens besides `v0`, `v1`, ...
+    names: Vec<Option<String>>,
     code: String,
+    prec: Option<Id>,
+    /// where the production was defined in the grammar file, if it comes from one
+    span: Option<Span>,
 }
 
 impl GrammarEntry {
@@ -89,10 +131,19 @@ impl GrammarEntry {
         &self.tokens
     }
 
+    /// the name the `i`-th token is bound to in the code, if any
+    pub fn name(&self, i: usize) -> Option<&str> {
+        self.names.get(i)?.as_deref()
+    }
+
     pub fn code(&self) -> &str {
         &self.code
     }
 
+    pub fn span(&self) -> Option<Span> {
+        self.span
+    }
+
     pub fn display(&self, f: &mut std::fmt::Formatter<'_>, pool: &Pool) -> std::fmt::Result {
         write!(f, "{} -> ", pool.get(self.rule_name))?;
         if self.tokens.is_empty() {
@@ -110,6 +161,18 @@ impl GrammarEntry {
 pub struct Grammar {
     pool: Pool,
     entries: Vec<GrammarEntry>,
+    precedence: HashMap<Id, Precedence>,
+    reduce_reduce: ReduceReduce,
+    /// the indices of the entries of each rule
+    by_rule: HashMap<Id, Vec<usize>>,
+    /// the rules that can derive the empty word
+    nullable: HashSet<Id>,
+    /// the terminals each rule can start with
+    firsts: HashMap<Id, BTreeSet<Token>>,
+    /// the rules added by [`GrammarBuilder::finish_with_entries`] that derive an entry rule
+    /// followed by the end of input, one per entry rule and in the same order. Their names do not
+    /// clash with any rule of the grammar.
+    starts: Vec<Id>,
 }
 
 impl Display for Grammar {
@@ -129,50 +192,64 @@ impl Grammar {
     }
 
     pub fn productions(&self, rule: Id) -> impl IntoIterator<Item = &[Token]> {
-        self.entries
+        self.entry_indices(rule)
             .iter()
-            .filter_map(move |entry| (entry.rule_name == rule).then_some(entry.tokens.as_slice()))
+            .map(|&i| self.entries[i].tokens.as_slice())
     }
 
-    pub fn first(&self, rules: &[Token]) -> BTreeSet<Token> {
-        match rules {
-            [] => BTreeSet::from([Token::Empty]),
-            [Token::Term(t), ..] => BTreeSet::from([Token::Term(*t)]),
-            [Token::Eof, ..] => BTreeSet::from([Token::Eof]),
-            [Token::NonTerm(nt), more @ ..] => {
-                let (this, other): (Vec<_>, Vec<_>) = self
-                    .productions(*nt)
-                    .into_iter()
-                    .partition_map(|prod| match prod {
-                        [Token::NonTerm(x), rest @ ..] if x == nt => Either::Left(rest),
-                        _ => Either::Right(prod),
-                    });
-
-                let mut firsts: BTreeSet<_> =
-                    other.into_iter().flat_map(|x| self.first(x)).collect();
-
-                if firsts.contains(&Token::Empty) && !this.is_empty() {
-                    if !more.is_empty() {
-                        firsts.remove(&Token::Empty);
-                    }
+    /// the positions of the productions of `rule` in the grammar
+    pub fn entry_indices(&self, rule: Id) -> &[usize] {
+        self.by_rule.get(&rule).map_or(&[], Vec::as_slice)
+    }
 
-                    firsts.remove(&Token::Empty);
-                    firsts = firsts
-                        .union(&this.into_iter().flat_map(|x| self.first(x)).collect())
-                        .copied()
-                        .collect();
+    /// The terminals a word starting with `rules` can begin with. Contains [`Token::Empty`] if
+    /// `rules` can derive the empty word.
+    pub fn first(&self, rules: &[Token]) -> BTreeSet<Token> {
+        let mut firsts = BTreeSet::new();
+        for token in rules {
+            match token {
+                Token::Term(_) | Token::Eof => {
+                    firsts.insert(*token);
+                    return firsts;
                 }
-
-                if firsts.is_empty() || firsts.contains(&Token::Empty) {
-                    firsts.remove(&Token::Empty);
-
-                    firsts.union(&self.first(more)).copied().collect()
-                } else {
-                    firsts
+                Token::NonTerm(nt) => {
+                    if let Some(first) = self.firsts.get(nt) {
+                        firsts.extend(first);
+                    }
+                    if !self.nullable.contains(nt) {
+                        return firsts;
+                    }
                 }
+                Token::Empty => unreachable!("rules may not contain empty token"),
             }
-            [_, ..] => unreachable!("rules may not contain empty token"),
         }
+        firsts.insert(Token::Empty);
+        firsts
+    }
+
+    /// The start rules in the order of the entry rules, see [`Grammar::is_start`]. The parser for
+    /// the `i`-th of them begins in state `i + 1`, see [`Graph::make`](crate::generator::Graph).
+    pub fn starts(&self) -> &[Id] {
+        &self.starts
+    }
+
+    /// Whether `rule` is a start rule `start -> entry $`. It is not part of the grammar the user
+    /// wrote, so it has no code and only exists to accept the input.
+    pub fn is_start(&self, rule: Id) -> bool {
+        self.starts.contains(&rule)
+    }
+
+    /// the rule the start rule `start` derives
+    pub fn entry_rule(&self, start: Id) -> Id {
+        let start = &self.entries[self.entry_indices(start)[0]];
+        start.tokens[0]
+            .non_term()
+            .expect("the start rule derives the entry rule")
+    }
+
+    /// the entry rules in the order they were given
+    pub fn entry_rules(&self) -> impl Iterator<Item = Id> + '_ {
+        self.starts.iter().map(|&x| self.entry_rule(x))
     }
 
     pub fn initial(&self, rule: Id) -> impl IntoIterator<Item = State> + '_ {
@@ -185,6 +262,49 @@ impl Grammar {
         &self.entries
     }
 
+    /// the position of the production `rule -> expansion` in the grammar
+    pub fn entry_index(&self, rule: Id, expansion: &[Token]) -> Option<usize> {
+        self.entry_indices(rule)
+            .iter()
+            .copied()
+            .find(|&i| self.entries[i].tokens == expansion)
+    }
+
+    pub fn reduce_reduce(&self) -> ReduceReduce {
+        self.reduce_reduce
+    }
+
+    /// the declared precedence of a terminal
+    pub fn precedence(&self, term: Id) -> Option<Precedence> {
+        self.precedence.get(&term).copied()
+    }
+
+    /// The precedence of a production. This is the precedence of the `%prec` terminal if one is
+    /// given, otherwise that of the last terminal in the production.
+    pub fn production_precedence(&self, entry: &GrammarEntry) -> Option<Precedence> {
+        let term = entry
+            .prec
+            .or_else(|| entry.tokens.iter().rev().find_map(|x| x.term()))?;
+        self.precedence(term)
+    }
+
+    /// Decides a shift/reduce conflict between reducing `entry` and shifting `lookahead` the way
+    /// yacc does. Returns `None` if either of them has no precedence.
+    pub fn resolve(&self, entry: &GrammarEntry, lookahead: Token) -> Option<Resolution> {
+        let rule = self.production_precedence(entry)?;
+        let token = self.precedence(lookahead.term()?)?;
+        let resolution = match rule.level.cmp(&token.level) {
+            std::cmp::Ordering::Less => Resolution::Shift,
+            std::cmp::Ordering::Greater => Resolution::Reduce,
+            std::cmp::Ordering::Equal => match token.assoc {
+                Assoc::Left => Resolution::Reduce,
+                Assoc::Right => Resolution::Shift,
+                Assoc::Nonassoc => Resolution::Error,
+            },
+        };
+        Some(resolution)
+    }
+
     pub fn pool_mut(&mut self) -> &mut Pool {
         &mut self.pool
     }
@@ -198,6 +318,9 @@ impl Grammar {
 pub struct GrammarBuilder {
     string_pool: Pool,
     entries: Vec<GrammarEntry>,
+    precedence: HashMap<Id, Precedence>,
+    levels: usize,
+    reduce_reduce: ReduceReduce,
 }
 
 impl GrammarBuilder {
@@ -205,9 +328,39 @@ impl GrammarBuilder {
         GrammarBuilder {
             string_pool: Pool::new(),
             entries: Vec::new(),
+            precedence: HashMap::new(),
+            levels: 0,
+            reduce_reduce: ReduceReduce::Error,
         }
     }
 
+    /// sets how reduce/reduce conflicts are handled
+    pub fn reduce_reduce(mut self, reduce_reduce: ReduceReduce) -> Self {
+        self.reduce_reduce = reduce_reduce;
+        self
+    }
+
+    /// Declares a new precedence level containing `terms`. Every call binds tighter than the
+    /// previous ones, just like consecutive `%left` lines in yacc.
+    pub fn precedence<I, S>(mut self, assoc: Assoc, terms: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: ToOwned<Owned = String>,
+    {
+        self.levels += 1;
+        for term in terms {
+            let term = self.string_pool.add(term);
+            self.precedence.insert(
+                term,
+                Precedence {
+                    level: self.levels,
+                    assoc,
+                },
+            );
+        }
+        self
+    }
+
     pub fn production<S>(mut self, rule: S, prod: ProductionBuilt, code: String) -> Self
     where
         S: ToOwned<Owned = String>,
@@ -216,41 +369,146 @@ impl GrammarBuilder {
         self.entries.push(GrammarEntry {
             rule_name,
             tokens: prod.tokens,
+            names: prod.names,
             code,
+            prec: prod.prec,
+            span: prod.span,
         });
         self
     }
 
-    pub fn prod_builder(&mut self) -> ProductionBuilder {
+    pub fn prod_builder(&mut self) -> ProductionBuilder<'_> {
         ProductionBuilder {
             pool: &mut self.string_pool,
             tokens: Vec::new(),
+            names: Vec::new(),
+            prec: None,
+            span: None,
         }
     }
 
-    pub fn finish(mut self, entry_point: String) -> Grammar {
-        let super_rule = self.string_pool.add("S0".to_owned());
-        let entry_point = self.string_pool.add(entry_point);
-        self.entries.push(GrammarEntry {
-            rule_name: super_rule,
-            tokens: Vec::from([Token::NonTerm(entry_point), Token::Eof]),
-            code: "".to_owned(),
-        });
+    pub fn finish(self, entry_point: String) -> Grammar {
+        self.finish_with_entries(vec![entry_point])
+    }
+
+    /// Finishes a grammar that can be parsed starting from any of `entry_points`. Each of them gets
+    /// its own start rule, all of them share the states of the parser. Entry points that are given
+    /// more than once only get one start rule.
+    pub fn finish_with_entries(self, entry_points: Vec<String>) -> Grammar {
+        self.finish_with_spanned_entries(entry_points.into_iter().map(|x| (x, None)).collect())
+    }
+
+    /// Like [`GrammarBuilder::finish_with_entries`], with the spans that name the entry rules. The
+    /// start rule of an entry gets its span, so [`Grammar::analyze`] can point at undefined ones.
+    pub fn finish_with_spanned_entries(
+        mut self,
+        entry_points: Vec<(String, Option<Span>)>,
+    ) -> Grammar {
+        let mut starts = Vec::new();
+        let mut seen = HashSet::new();
+        for (entry_point, span) in entry_points {
+            let entry_point = self.string_pool.add(entry_point);
+            if !seen.insert(entry_point) {
+                continue;
+            }
+            // only the generated code sees the names of the start rules, they just have to be unused
+            let mut name = format!("S{}", starts.len());
+            while self.string_pool.get_reverse(&name).is_some() {
+                name.push('_');
+            }
+            let super_rule = self.string_pool.add(name);
+            self.entries.push(GrammarEntry {
+                rule_name: super_rule,
+                tokens: Vec::from([Token::NonTerm(entry_point), Token::Eof]),
+                names: Vec::new(),
+                code: "".to_owned(),
+                prec: None,
+                span,
+            });
+            starts.push(super_rule);
+        }
+
+        let mut by_rule: HashMap<Id, Vec<usize>> = HashMap::new();
+        for (i, entry) in self.entries.iter().enumerate() {
+            by_rule.entry(entry.rule_name).or_default().push(i);
+        }
+        let (nullable, firsts) = first_sets(&self.entries);
 
         Grammar {
             pool: self.string_pool,
             entries: self.entries,
+            precedence: self.precedence,
+            reduce_reduce: self.reduce_reduce,
+            by_rule,
+            nullable,
+            firsts,
+            starts,
         }
     }
 }
 
+/// computes the nullable rules and the first sets of all rules by iterating until nothing changes
+fn first_sets(entries: &[GrammarEntry]) -> (HashSet<Id>, HashMap<Id, BTreeSet<Token>>) {
+    let mut nullable = HashSet::new();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for entry in entries {
+            let derives_empty = entry
+                .tokens
+                .iter()
+                .all(|x| matches!(x, Token::NonTerm(nt) if nullable.contains(nt)));
+            if derives_empty {
+                changed |= nullable.insert(entry.rule_name);
+            }
+        }
+    }
+
+    let mut firsts: HashMap<Id, BTreeSet<Token>> = HashMap::new();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for entry in entries {
+            let mut first = BTreeSet::new();
+            for token in &entry.tokens {
+                match token {
+                    Token::NonTerm(nt) => {
+                        if let Some(x) = firsts.get(nt) {
+                            first.extend(x);
+                        }
+                        if !nullable.contains(nt) {
+                            break;
+                        }
+                    }
+                    _ => {
+                        first.insert(*token);
+                        break;
+                    }
+                }
+            }
+            let known = firsts.entry(entry.rule_name).or_default();
+            let len = known.len();
+            known.extend(first);
+            changed |= known.len() != len;
+        }
+    }
+
+    (nullable, firsts)
+}
+
 pub struct ProductionBuilder<'a> {
     pool: &'a mut Pool,
     tokens: Vec<Token>,
+    names: Vec<Option<String>>,
+    prec: Option<Id>,
+    span: Option<Span>,
 }
 
 pub struct ProductionBuilt {
     tokens: Vec<Token>,
+    names: Vec<Option<String>>,
+    prec: Option<Id>,
+    span: Option<Span>,
 }
 
 impl ProductionBuilder<'_> {
@@ -260,6 +518,7 @@ impl ProductionBuilder<'_> {
     {
         let term = self.pool.add(term);
         self.tokens.push(Token::Term(term));
+        self.names.push(None);
         self
     }
 
@@ -269,12 +528,44 @@ impl ProductionBuilder<'_> {
     {
         let non_term = self.pool.add(non_term);
         self.tokens.push(Token::NonTerm(non_term));
+        self.names.push(None);
+        self
+    }
+
+    /// lets the code refer to the value of the symbol added last by `name`
+    pub fn bind<S>(mut self, name: S) -> Self
+    where
+        S: ToOwned<Owned = String>,
+    {
+        let last = self
+            .names
+            .last_mut()
+            .expect("a symbol has to be added before it can be bound");
+        *last = Some(name.to_owned());
+        self
+    }
+
+    /// gives the production the precedence of `term`, like `%prec` in yacc
+    pub fn prec<S>(mut self, term: S) -> Self
+    where
+        S: ToOwned<Owned = String>,
+    {
+        self.prec = Some(self.pool.add(term));
+        self
+    }
+
+    /// records where the production is defined in the grammar file
+    pub fn span(mut self, span: Span) -> Self {
+        self.span = Some(span);
         self
     }
 
     pub fn finish(self) -> ProductionBuilt {
         ProductionBuilt {
             tokens: self.tokens,
+            names: self.names,
+            prec: self.prec,
+            span: self.span,
         }
     }
 }
@@ -416,4 +707,69 @@ mod tests {
         let prods: BTreeSet<&[Token]> = g.productions(b.non_term().unwrap()).into_iter().collect();
         assert_eq!(prods, BTreeSet::from([&[][..], &[c][..],]));
     }
+
+    #[test]
+    fn precedence_resolution() {
+        let s = String::from;
+        let mut builder = Grammar::builder()
+            .precedence(Assoc::Left, [s("plus")])
+            .precedence(Assoc::Right, [s("pow")])
+            .precedence(Assoc::Nonassoc, [s("eq"), s("neg")]);
+        for op in ["plus", "pow", "eq"] {
+            let prod = builder
+                .prod_builder()
+                .non_term(s("E"))
+                .term(s(op))
+                .non_term(s("E"))
+                .finish();
+            builder = builder.production(s("E"), prod, s(""));
+        }
+        let prod = builder
+            .prod_builder()
+            .term(s("minus"))
+            .non_term(s("E"))
+            .prec(s("neg"))
+            .finish();
+        let g = builder.production(s("E"), prod, s("")).finish(s("E"));
+
+        let [plus, pow, eq, neg] = [0, 1, 2, 3].map(|i| &g.entries()[i]);
+        let [t_plus, t_pow, t_eq] = ["plus", "pow", "eq"].map(|x| term(&g, x));
+        let minus = term(&g, "minus");
+
+        assert_eq!(g.resolve(plus, t_plus), Some(Resolution::Reduce));
+        assert_eq!(g.resolve(plus, t_pow), Some(Resolution::Shift));
+        assert_eq!(g.resolve(pow, t_pow), Some(Resolution::Shift));
+        assert_eq!(g.resolve(pow, t_plus), Some(Resolution::Reduce));
+        assert_eq!(g.resolve(eq, t_eq), Some(Resolution::Error));
+        assert_eq!(g.resolve(neg, t_pow), Some(Resolution::Reduce));
+        assert_eq!(g.resolve(plus, minus), None);
+    }
+
+    #[test]
+    fn start_rule_is_out_of_band() {
+        let g = grammar!(
+            S0:
+            S0 => N "S0_" @ "";
+            S0_ => T "x" @ "";
+        );
+        let user_rule = g.pool().get_reverse("S0").unwrap();
+        assert!(!g.is_start(user_rule));
+        assert_eq!(g.pool().get(g.starts()[0]), "S0__");
+        assert_eq!(g.entry_rule(g.starts()[0]), user_rule);
+    }
+
+    #[test]
+    fn start_rule_per_entry() {
+        let mut builder = Grammar::builder();
+        for (rule, term) in [("Prog", "x"), ("Expr", "y"), ("S1", "z")] {
+            let prod = builder.prod_builder().term(term.to_owned()).finish();
+            builder = builder.production(rule.to_owned(), prod, String::new());
+        }
+        let entries = ["Prog", "Expr", "Prog"].map(String::from);
+        let g = builder.finish_with_entries(entries.to_vec());
+        let starts: Vec<_> = g.starts().iter().map(|&x| g.pool().get(x)).collect();
+        assert_eq!(starts, ["S0", "S1_"]);
+        let entries: Vec<_> = g.entry_rules().map(|x| g.pool().get(x)).collect();
+        assert_eq!(entries, ["Prog", "Expr"]);
+    }
 }
@@ -0,0 +1,244 @@
+//! Sanity checks on a grammar that are cheaper and more to the point than the conflicts they would
+//! otherwise cause while building the table
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::{
+    diagnostics::{suggest, Diagnostic},
+    string_pool::Id,
+};
+
+use super::{Grammar, GrammarEntry, Token};
+
+impl Grammar {
+    /// Reports rules that can't be reached from any entry rule (warnings), rules that never derive
+    /// a sequence of terminals and productions that are declared twice (errors). Undefined entry
+    /// rules are also errors.
+    pub fn analyze(&self) -> Vec<Diagnostic> {
+        let pool = self.pool();
+        let entries: Vec<Id> = self.entry_rules().collect();
+
+        let mut diagnostics = Vec::new();
+        let mut entries_defined = true;
+        for (&start, &entry) in self.starts.iter().zip(&entries) {
+            if !self.entry_indices(entry).is_empty() {
+                continue;
+            }
+            entries_defined = false;
+            let rules = self.rules().into_iter().map(|x| pool.get(x));
+            let mut diagnostic =
+                Diagnostic::error(format!("entry rule `{}` is not defined", pool.get(entry)));
+            if let Some(span) = self.entries[self.entry_indices(start)[0]].span {
+                diagnostic = diagnostic.label(span, "not a rule");
+            }
+            if let Some(rule) = suggest(pool.get(entry), rules) {
+                diagnostic =
+                    diagnostic.help(format!("a rule with a similar name exists: `{rule}`"));
+            }
+            diagnostics.push(diagnostic);
+        }
+
+        // without all entry rules, rules could seem unreachable that are used by the missing ones
+        let reachable = self.reachable(&self.starts);
+        let from = match entries.as_slice() {
+            [entry] => format!("the entry rule `{}`", pool.get(*entry)),
+            _ => "any entry rule".to_owned(),
+        };
+        for rule in self.rules() {
+            if entries_defined && !reachable.contains(&rule) {
+                let first = &self.entries[self.entry_indices(rule)[0]];
+                let mut diagnostic =
+                    Diagnostic::warning(format!("rule `{}` is never used", pool.get(rule)));
+                if let Some(span) = first.span {
+                    diagnostic = diagnostic.label(span, format!("unreachable from {from}"));
+                }
+                diagnostics.push(diagnostic);
+            }
+        }
+
+        let productive = self.productive();
+        for rule in self.rules() {
+            if productive.contains(&rule) {
+                continue;
+            }
+            let mut diagnostic = Diagnostic::error(format!(
+                "rule `{}` never derives a sequence of terminals",
+                pool.get(rule)
+            ))
+            .note("every production refers to a rule that never finishes deriving");
+            for (i, &index) in self.entry_indices(rule).iter().enumerate() {
+                let entry = &self.entries[index];
+                let Some(span) = entry.span else { continue };
+                let culprit = entry
+                    .tokens
+                    .iter()
+                    .find_map(|x| x.non_term().filter(|x| !productive.contains(x)))
+                    .expect("unproductive productions contain an unproductive rule");
+                let message = format!("uses `{}`", pool.get(culprit));
+                diagnostic = match i {
+                    0 => diagnostic.label(span, message),
+                    _ => diagnostic.secondary(span, message),
+                };
+            }
+            diagnostics.push(diagnostic);
+        }
+
+        let mut declared: HashMap<(Id, &[Token]), &GrammarEntry> = HashMap::new();
+        for entry in &self.entries {
+            let Some(first) = declared.get(&(entry.rule_name, &entry.tokens)) else {
+                declared.insert((entry.rule_name, &entry.tokens), entry);
+                continue;
+            };
+            let mut production = format!("{} ->", pool.get(entry.rule_name));
+            for token in &entry.tokens {
+                production.push_str(&format!(" {}", token.display(pool)));
+            }
+            let mut diagnostic = Diagnostic::error(format!("duplicate production {production}"))
+                .note("only the code of the first declaration would ever run");
+            if let Some(span) = entry.span {
+                diagnostic = diagnostic.label(span, "declared again here");
+            }
+            if let Some(span) = first.span {
+                diagnostic = diagnostic.secondary(span, "first declared here");
+            }
+            diagnostics.push(diagnostic);
+        }
+
+        diagnostics
+    }
+
+    /// the rules in the order they are declared, without the start rule
+    fn rules(&self) -> Vec<Id> {
+        let mut seen = HashSet::new();
+        self.entries
+            .iter()
+            .map(|x| x.rule_name)
+            .filter(|x| !self.is_start(*x) && seen.insert(*x))
+            .collect()
+    }
+
+    /// the rules that appear in some derivation of one of `starts`
+    fn reachable(&self, starts: &[Id]) -> HashSet<Id> {
+        let mut reachable: HashSet<Id> = starts.iter().copied().collect();
+        let mut queue: VecDeque<Id> = starts.iter().copied().collect();
+        while let Some(rule) = queue.pop_front() {
+            for production in self.productions(rule) {
+                for non_term in production.iter().filter_map(|x| x.non_term()) {
+                    if reachable.insert(non_term) {
+                        queue.push_back(non_term);
+                    }
+                }
+            }
+        }
+        reachable
+    }
+
+    /// the rules that derive at least one sequence of terminals
+    fn productive(&self) -> HashSet<Id> {
+        let mut productive = HashSet::new();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for entry in &self.entries {
+                let all_productive = entry
+                    .tokens
+                    .iter()
+                    .all(|x| x.non_term().is_none_or(|x| productive.contains(&x)));
+                if all_productive {
+                    changed |= productive.insert(entry.rule_name);
+                }
+            }
+        }
+        productive
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(g: &Grammar) -> Vec<String> {
+        g.analyze().into_iter().map(|x| x.message).collect()
+    }
+
+    #[test]
+    fn sane_grammar() {
+        let g = crate::grammar!(
+            E:
+            E => N "E" T "plus" N "T" @ "";
+            E => N "T" @ "";
+            T => T "x" @ "";
+        );
+        assert!(g.analyze().is_empty());
+    }
+
+    #[test]
+    fn unreachable_rules() {
+        let g = crate::grammar!(
+            E:
+            E => T "x" @ "";
+            A => N "B" @ "";
+            B => T "y" @ "";
+        );
+        let diagnostics = g.analyze();
+        assert!(diagnostics.iter().all(|x| !x.is_error()));
+        assert_eq!(
+            messages(&g),
+            ["rule `B` is never used", "rule `A` is never used"]
+        );
+    }
+
+    #[test]
+    fn unproductive_rules() {
+        let g = crate::grammar!(
+            E:
+            E => N "A" @ "";
+            E => T "x" @ "";
+            A => T "y" N "A" @ "";
+        );
+        let diagnostics = g.analyze();
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].is_error());
+        assert_eq!(
+            diagnostics[0].message,
+            "rule `A` never derives a sequence of terminals"
+        );
+    }
+
+    #[test]
+    fn duplicate_productions() {
+        let g = crate::grammar!(
+            E:
+            E => T "x" @ "1";
+            E => T "y" @ "";
+            E => T "x" @ "2";
+        );
+        assert_eq!(messages(&g), ["duplicate production E -> `x`"]);
+    }
+
+    #[test]
+    fn rules_used_by_any_entry_are_reachable() {
+        let mut builder = Grammar::builder();
+        for (rule, term) in [("Prog", "x"), ("Expr", "y"), ("Unused", "z")] {
+            let prod = builder.prod_builder().term(term.to_owned()).finish();
+            builder = builder.production(rule.to_owned(), prod, String::new());
+        }
+        let g = builder.finish_with_entries(vec!["Prog".to_owned(), "Expr".to_owned()]);
+        assert_eq!(messages(&g), ["rule `Unused` is never used"]);
+    }
+
+    #[test]
+    fn undefined_entry() {
+        let g = crate::grammar!(
+            Exr:
+            Expr => T "x" @ "";
+        );
+        let diagnostics = g.analyze();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "entry rule `Exr` is not defined");
+        assert_eq!(
+            diagnostics[0].help,
+            ["a rule with a similar name exists: `Expr`"]
+        );
+    }
+}
@@ -1,20 +1,39 @@
 use std::path::PathBuf;
+use std::process::ExitCode;
 use std::{io::Write, path::Path};
 
 use anyhow::{anyhow, bail, Context};
 use clap::Parser;
 use tracing::{info, warn};
 
-use crate::{frontends::Render, generator::Graph, spec::parse_string, table::Table};
+use crate::{
+    diagnostics::Diagnostic, frontends::Render, generator::Graph, source::Source,
+    spec::parse_string, table::Table,
+};
 
+mod counterexample;
+mod diagnostics;
 mod frontends;
 
 mod generator;
 mod grammar;
+mod source;
 mod spec;
 mod string_pool;
 mod table;
 
+/// how the states of the parser are constructed
+#[derive(clap::ValueEnum, Debug, Clone, Copy, Default)]
+enum Algorithm {
+    /// canonical LR(1)
+    #[default]
+    Lr1,
+    /// LALR(1), merges states with identical LR(0) cores
+    Lalr,
+    /// minimal LR(1), merges states with identical LR(0) cores unless that introduces conflicts
+    Minimal,
+}
+
 #[derive(clap::Parser, Debug)]
 #[clap(version, author)]
 struct Cli {
@@ -48,6 +67,10 @@ struct Cli {
     #[clap(short, long)]
     bootstrap: bool,
 
+    /// the parser construction algorithm
+    #[clap(short, long, value_enum, default_value_t)]
+    algorithm: Algorithm,
+
     /// Input file where all the definitions are placed.
     ///
     /// For informations on the syntax, consider the relevant documentation
@@ -80,61 +103,117 @@ where
     Ok(())
 }
 
-fn main() -> anyhow::Result<()> {
+/// prints `error` to stderr, with a snippet of `source` if the error points into the grammar file
+fn report(error: &anyhow::Error, source: Option<Source>) {
+    match error.downcast_ref::<Diagnostic>() {
+        Some(diagnostic) => diagnostic.emit(source),
+        None => Diagnostic::error(format!("{error:#}")).emit(None),
+    }
+}
+
+fn main() -> ExitCode {
     let cli = Cli::parse();
     tracing_subscriber::fmt::init();
 
     if cli.bootstrap {
         warn!("--bootstrap is to bootstrap asterisk-rs itself. is this really what you want?");
-        spec::bootstrap(&cli.grammar).context("could not bootstrap")?;
+        if let Err(e) = spec::bootstrap(&cli.grammar).context("could not bootstrap") {
+            report(&e, None);
+            return ExitCode::FAILURE;
+        }
 
-        return Ok(());
+        return ExitCode::SUCCESS;
     }
 
     info!("reading {:?} as grammar file", cli.grammar);
-    let grammar = std::fs::read_to_string(&cli.grammar).expect("failed to read grammar");
-    let (grammar, visitor) = parse_string(&grammar)?;
-    let a = grammar
-        .pool()
-        .get_reverse("S0")
-        .expect("S0 should be in grammar");
-    let graph = Graph::make(&grammar, grammar.initial(a).into_iter().collect());
+    let text = match std::fs::read_to_string(&cli.grammar)
+        .with_context(|| format!("failed to read grammar file {}", cli.grammar))
+    {
+        Ok(text) => text,
+        Err(e) => {
+            report(&e, None);
+            return ExitCode::FAILURE;
+        }
+    };
+    let source = Source::new(&cli.grammar, &text);
+    match run(&cli, source) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            report(&e, Some(source));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: &Cli, source: Source) -> anyhow::Result<()> {
+    let (grammar, visitor) = parse_string(source.name(), source.text())?;
+    let diagnostics = grammar.analyze();
+    for diagnostic in &diagnostics {
+        diagnostic.emit(Some(source));
+    }
+    match diagnostics.iter().filter(|x| x.is_error()).count() {
+        0 => {}
+        1 => bail!(Diagnostic::error(
+            "could not generate a parser because of the previous error"
+        )),
+        n => bail!(Diagnostic::error(format!(
+            "could not generate a parser because of {n} previous errors"
+        ))),
+    }
+
+    let graph = Graph::make(&grammar);
+    let graph = match cli.algorithm {
+        Algorithm::Lr1 => graph,
+        Algorithm::Lalr => {
+            let (graph, conflicts) = graph.lalr();
+            for conflict in conflicts {
+                conflict.diagnostic(&grammar).emit(Some(source));
+            }
+            graph
+        }
+        Algorithm::Minimal => graph.minimal(),
+    };
 
     if cli.emit_dot {
         let output_dir = cli
             .output_dir
+            .as_ref()
             .ok_or(anyhow!("output dir must be set to emit graphviz"))?;
         let mut p = PathBuf::from(output_dir);
         p.push("dfa.dot");
-        let mut f = std::fs::File::create(&p).unwrap();
-        writeln!(f, "{}", graph.print(grammar.pool())).unwrap();
+        let mut f = std::fs::File::create(&p)
+            .with_context(|| format!("failed to create {}", p.display()))?;
+        writeln!(f, "{}", graph.print(grammar.pool()))?;
 
         run_graphviz(&p).context("failed to run graphviz")?;
     }
 
-    let table = match Table::from_graph(&graph) {
+    let table = match Table::from_graph(&graph, &grammar) {
         Ok(t) => t,
-        Err(conflict) => {
-            bail!(
-                "could not construct table because of conflict in {}: token={}  \neither: {}\nor:     {}",
-                conflict.state,
-                conflict.token.display(grammar.pool()),
-                conflict.either.display(grammar.pool()),
-                conflict.or.display(grammar.pool())
-            );
+        Err(conflicts) => {
+            let examples = counterexample::explain(&graph, &grammar, &conflicts.0);
+            for diagnostic in conflicts.diagnostics(&graph, &grammar, &examples) {
+                diagnostic.emit(Some(source));
+            }
+            bail!(Diagnostic::error(format!(
+                "could not construct table: {}",
+                conflicts.summary()
+            )));
         }
     };
 
-    if let Some(output) = cli.output {
-        let mut f = std::fs::File::create(&output).unwrap();
+    let render = Render::new(&visitor, &table, &grammar)?;
+    if let Some(output) = &cli.output {
+        let mut f =
+            std::fs::File::create(output).with_context(|| format!("failed to create {output}"))?;
         info!("writing to {output}");
-        writeln!(f, "{}", Render::new(&visitor, &table, &grammar)).unwrap();
+        writeln!(f, "{render}")?;
 
         if cli.format {
-            visitor.format(&output).context("failed to format")?;
+            visitor.format(output).context("failed to format")?;
         }
     } else {
-        println!("{}", Render::new(&visitor, &table, &grammar));
+        println!("{render}");
     }
 
     Ok(())
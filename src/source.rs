@@ -0,0 +1,111 @@
+use std::ops::Range;
+
+/// A range of bytes in a grammar file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// the span from the start of `self` to the end of `other`
+    pub fn to(self, other: Span) -> Span {
+        Span::new(self.start, other.end)
+    }
+}
+
+impl From<Range<usize>> for Span {
+    fn from(range: Range<usize>) -> Self {
+        Span::new(range.start, range.end)
+    }
+}
+
+/// A value together with the place in the grammar file it was read from
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub span: Span,
+}
+
+impl<T> Spanned<T> {
+    pub fn new(value: T, span: impl Into<Span>) -> Self {
+        Self {
+            value,
+            span: span.into(),
+        }
+    }
+}
+
+/// only shows the value, spans make error messages that print tokens unreadable
+impl<T: std::fmt::Debug> std::fmt::Debug for Spanned<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+/// A grammar file together with its name, used to point at locations in error messages
+#[derive(Debug, Clone, Copy)]
+pub struct Source<'a> {
+    name: &'a str,
+    text: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(name: &'a str, text: &'a str) -> Self {
+        Self { name, text }
+    }
+
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// the line and column of a byte offset, both starting at 1. Columns count characters
+    pub fn line_col(&self, offset: usize) -> (usize, usize) {
+        let before = &self.text[..offset.min(self.text.len())];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |x| x + 1);
+        (line, before[line_start..].chars().count() + 1)
+    }
+
+    /// the span of the line containing `offset`, without the line break
+    pub fn line_span(&self, offset: usize) -> Span {
+        let offset = offset.min(self.text.len());
+        let start = self.text[..offset].rfind('\n').map_or(0, |x| x + 1);
+        let end = self.text[offset..]
+            .find('\n')
+            .map_or(self.text.len(), |x| offset + x);
+        Span::new(start, end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_col() {
+        let source = Source::new("test.ast", "ab\ncäd\n");
+        assert_eq!(source.line_col(0), (1, 1));
+        assert_eq!(source.line_col(3), (2, 1));
+        // `ä` is two bytes long but a single column
+        assert_eq!(source.line_col(6), (2, 3));
+        assert_eq!(source.line_col(8), (3, 1));
+    }
+
+    #[test]
+    fn line_span() {
+        let source = Source::new("test.ast", "ab\ncd\n");
+        assert_eq!(source.line_span(1), Span::new(0, 2));
+        assert_eq!(source.line_span(2), Span::new(0, 2));
+        assert_eq!(source.line_span(4), Span::new(3, 5));
+        assert_eq!(source.line_span(6), Span::new(6, 6));
+    }
+}
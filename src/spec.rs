@@ -1,24 +1,35 @@
-use std::{collections::HashMap, fs::File, io::Write};
+use std::{
+    collections::{HashMap, HashSet},
+    fs::File,
+    io::Write,
+};
 
-use ansi_term::Color;
-use anyhow::{anyhow, bail, Context};
+use anyhow::{bail, Context};
 use itertools::Itertools;
 use logos::Logos;
 use tracing::info;
 
 use crate::{
-    frontends::{ocaml::OcamlVisitor, python::Python, rust::Rust, Format, Frontend, Render},
+    counterexample,
+    diagnostics::Diagnostic,
+    frontends::{
+        ocaml::OcamlVisitor, python::Python, rust::Rust, rust_table::RustTable, Format, Frontend,
+        Render,
+    },
     generator::Graph,
-    grammar::Grammar,
+    grammar::{Grammar, ReduceReduce},
     run_graphviz,
+    source::{Source, Span, Spanned},
     table::Table,
 };
 
-use self::lex::Token;
+use self::{lex::Token, parser::TokenKind};
 
 mod ast;
+mod check;
 mod lex;
 mod parser;
+mod sugar;
 
 #[allow(dead_code)]
 fn own_grammar() -> Grammar {
@@ -61,10 +72,8 @@ fn own_visitor() -> Rust {
             p("Idents", "Vec<String>"),
         ]),
         HashMap::from([p("Ident", "String"), p("Literal", "String")]),
-        s("Grammar"),
         s("Token"),
     )
-    .use_default_for_token()
 }
 
 #[tracing::instrument]
@@ -73,8 +82,7 @@ pub fn bootstrap(filename: &str) -> anyhow::Result<()> {
     let mut f = File::create(filename).context("could not create output file")?;
     let mut grammar = own_grammar();
     info!("got grammar:\n{grammar}");
-    let s0 = grammar.pool_mut().add("S0".to_owned());
-    let graph = Graph::make(&grammar, grammar.initial(s0).into_iter().collect());
+    let graph = Graph::make(&grammar);
 
     {
         let mut f = std::fs::File::create("output/tmp.dot").unwrap();
@@ -84,22 +92,23 @@ pub fn bootstrap(filename: &str) -> anyhow::Result<()> {
         run_graphviz(&"output/tmp.dot")?;
     }
 
-    let table = match Table::from_graph(&graph) {
+    let table = match Table::from_graph(&graph, &grammar) {
         Ok(t) => t,
-        Err(conflict) => {
-            bail!(
-                "could not construct table because of conflict in {}: token={}  \neither: {}\nor:     {}",
-                conflict.state,
-                conflict.token.display(grammar.pool()),
-                conflict.either.display(grammar.pool()),
-                conflict.or.display(grammar.pool())
-            );
+        Err(conflicts) => {
+            let examples = counterexample::explain(&graph, &grammar, &conflicts.0);
+            for diagnostic in conflicts.diagnostics(&graph, &grammar, &examples) {
+                diagnostic.emit(None);
+            }
+            bail!(Diagnostic::error(format!(
+                "could not construct table: {}",
+                conflicts.summary()
+            )));
         }
     };
 
     let visitor = own_visitor();
 
-    writeln!(f, "{}", Render::new(&visitor, &table, &grammar))
+    writeln!(f, "{}", Render::new(&visitor, &table, &grammar)?)
         .context("failed to write to file")?;
 
     visitor.format(filename)?;
@@ -107,164 +116,491 @@ pub fn bootstrap(filename: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
-#[tracing::instrument]
-pub fn parse_string(s: &str) -> anyhow::Result<(Grammar, Box<dyn Frontend>)> {
-    let toks = Token::lexer(s).map(|x| x.expect("lexing error"));
+/// splits the grammar file into tokens, reporting the location of the first invalid one
+fn lex(source: Source) -> anyhow::Result<Vec<(Token, Span)>> {
+    let mut lexer = Token::lexer(source.text());
+    let mut tokens = Vec::new();
+    while let Some(token) = lexer.next() {
+        let span = Span::from(lexer.span());
+        match token {
+            Ok(token) => tokens.push((token, span)),
+            Err(e) => bail!(Diagnostic::error(e.to_string()).label(span, "")),
+        }
+    }
+    Ok(tokens)
+}
+
+/// the error for a token the spec parser did not expect, pointing at that token
+fn unexpected_token(
+    source: Source,
+    tokens: &[(Token, Span)],
+    expected: Vec<Option<TokenKind>>,
+    received: Option<Token>,
+    remaining: usize,
+) -> Diagnostic {
+    let expected = expected
+        .iter()
+        .map(|x| x.map_or("end of file", lex::describe))
+        .collect_vec();
+    let expected = match expected.split_last() {
+        Some((last, [])) => format!("expected {last}"),
+        Some((last, rest)) => format!("expected one of {} or {last}", rest.iter().format(", ")),
+        None => String::new(),
+    };
+    match received {
+        Some(received) => {
+            let (_, span) = tokens[tokens.len() - remaining - 1];
+            Diagnostic::error(format!("unexpected {received}")).label(span, expected)
+        }
+        None => {
+            let end = source.text().len();
+            Diagnostic::error("unexpected end of file").label(Span::new(end, end), expected)
+        }
+    }
+}
 
-    let spec = match parser::parse(toks) {
+/// The rules listed by the `entry` config, either a single rule like `entry = Prog` or several
+/// like `entry = { Prog, Expr }`. The spans point at the names of the rules.
+fn entry_points(entry: &Spanned<String>) -> Vec<Spanned<String>> {
+    // the value of a code literal starts after its `{`
+    let start = match entry.span.end - entry.span.start == entry.value.len() {
+        true => entry.span.start,
+        false => entry.span.start + 1,
+    };
+    let mut entry_points = Vec::new();
+    let mut name_start = None;
+    // a separator at the end finishes the last name
+    let chars = entry.value.char_indices().chain([(entry.value.len(), ',')]);
+    for (i, c) in chars {
+        let separator = c == ',' || c.is_whitespace();
+        match (separator, name_start) {
+            (true, Some(begin)) => {
+                let span = Span::new(start + begin, start + i);
+                entry_points.push(Spanned::new(entry.value[begin..i].to_owned(), span));
+                name_start = None;
+            }
+            (false, None) => name_start = Some(i),
+            _ => {}
+        }
+    }
+    entry_points
+}
+
+/// parses the contents `s` of the grammar file `filename`
+#[tracing::instrument(skip(s))]
+pub fn parse_string(filename: &str, s: &str) -> anyhow::Result<(Grammar, Box<dyn Frontend>)> {
+    let source = Source::new(filename, s);
+    let all_input = lex(source)?;
+
+    let mut spec = match parser::parse(all_input.iter().map(|x| x.0.clone())) {
         Ok(v) => v,
-        Err(e) => match e {
-            parser::Error::Msg(m) => bail!("{}", m),
-            parser::Error::UnexpectedToken {
+        Err(parser::Error::Msg(m)) => bail!(Diagnostic::error(m)),
+        Err(parser::Error::UnexpectedToken {
+            expected,
+            received,
+            state_id,
+            remaining_input,
+        }) => {
+            info!("spec parser failed in state {state_id}");
+            bail!(unexpected_token(
+                source,
+                &all_input,
                 expected,
                 received,
-                state_id,
-                remaining_input,
-            } => {
-                let all_input = Token::lexer(s)
-                    .map(|x| x.expect("lexing error"))
-                    .collect_vec();
-                use std::fmt::Write;
-                let mut error_message = String::new();
-                writeln!(
-                    error_message,
-                    "expected one of {{{}}}",
-                    expected
-                        .into_iter()
-                        .map(|tok| tok.map(|x| format!("{x:?}")).unwrap_or("EOF".to_owned()))
-                        .format(", ")
-                )?;
-                writeln!(
-                    error_message,
-                    "but received {} in state {state_id}",
-                    received
-                        .clone()
-                        .map(|x| format!("{:?}", x))
-                        .unwrap_or("EOF".to_owned())
-                )?;
-                writeln!(error_message, "relevant context:")?;
-                let pos = all_input.len() - remaining_input.len() - 1;
-                let start = pos.saturating_sub(5);
-                let len = pos - start;
-                write!(
-                    error_message,
-                    "{} ",
-                    all_input
-                        .iter()
-                        .skip(start)
-                        .take(len)
-                        .map(|x| format!("{}", Color::Yellow.paint(format!("{x:?}"))))
-                        .format(", ")
-                )?;
-                write!(
-                    error_message,
-                    "{} ",
-                    Color::Red.paint(format!("{:?}", &received))
-                )?;
-                writeln!(
-                    error_message,
-                    "{}",
-                    remaining_input
-                        .into_iter()
-                        .take(10)
-                        .map(|x| format!("{}", Color::Yellow.paint(format!("{x:?}"))))
-                        .format(", ")
-                )?;
-                bail!(error_message)
-            }
-        },
+                remaining_input.len()
+            ))
+        }
     };
 
-    let mut builder = Grammar::builder();
+    let token_type = find_case_insensitive(&spec.configs, "type_token")
+        .unwrap_or("token")
+        .to_owned();
+    let prelude = find_case_insensitive(&spec.configs, "prelude").unwrap_or_default();
+    let target = find_case_insensitive(&spec.configs, "target");
+    // only the rust target has locations
+    let locations = match (target, find_config(&spec.configs, "type_location")) {
+        (Some("rust"), Some(_)) => check::check_locations(&spec),
+        _ => Vec::new(),
+    };
+    let warnings = check::check_configs(&spec.configs, target)
+        .into_iter()
+        .chain(check::check_terminals(&spec, &token_type, prelude))
+        .chain(check::check_positions(&spec))
+        .chain(locations);
+    for warning in warnings {
+        warning.emit(Some(source));
+    }
 
-    let mut non_term_types = HashMap::new();
+    let Some(target) = find_config(&spec.configs, "target").cloned() else {
+        bail!(Diagnostic::error("missing config `target`")
+            .help("add `target = rust`, `target = ocaml` or `target = python`"));
+    };
+    let Some(syntax) = sugar::Syntax::of(&target.value) else {
+        bail!(
+            Diagnostic::error(format!("unsupported target language: `{}`", target.value))
+                .label(target.span, "expected `rust`, `ocaml` or `python`")
+        );
+    };
 
-    for rule in &spec.rules {
-        non_term_types.insert(rule.name.to_owned(), rule.typ.to_owned());
-        if rule.name == "S0" {
-            bail!("rule may not be called S0");
-        }
+    // like all other configs, the first value of a token wins
+    let term_types: HashMap<String, String> = spec
+        .configs
+        .iter()
+        .rev()
+        .filter_map(|(k, v)| {
+            let (l, r) = k.value.split_once('_')?;
+            if l.to_lowercase() != "token" {
+                return None;
+            }
+
+            Some((r.to_owned(), v.value.to_owned()))
+        })
+        .collect();
+
+    check::check_bindings(&spec, &target.value)?;
+    sugar::desugar(&mut spec, syntax, &term_types)?;
+
+    let mut builder = Grammar::builder();
+
+    let declared: HashSet<String> = spec
+        .precedence
+        .iter()
+        .flat_map(|x| &x.1)
+        .map(|x| x.value.clone())
+        .collect();
+    for (assoc, terms) in spec.precedence {
+        builder = builder.precedence(assoc, terms.into_iter().map(|x| x.value));
     }
 
+    let non_term_types: HashMap<String, String> = spec
+        .rules
+        .iter()
+        .map(|rule| (rule.name.value.to_owned(), rule.typ.value.to_owned()))
+        .collect();
+
     for rule in spec.rules {
         for expansion in rule.expansions {
             let mut prod_builder = builder.prod_builder();
-            for tok in expansion.tokens {
-                if non_term_types.contains_key(&tok) {
-                    prod_builder = prod_builder.non_term(tok);
+            for binding in expansion.items {
+                let ast::Item::Symbol(name) = binding.item.value else {
+                    unreachable!("shorthands are replaced by symbols while desugaring")
+                };
+                if non_term_types.contains_key(&name) {
+                    prod_builder = prod_builder.non_term(name);
                 } else {
-                    prod_builder = prod_builder.term(tok);
+                    prod_builder = prod_builder.term(name);
+                }
+                if let Some(name) = binding.name {
+                    prod_builder = prod_builder.bind(name.value);
                 }
             }
-            let prod = prod_builder.finish();
-            builder = builder.production(rule.name.to_owned(), prod, expansion.code);
+            if let Some(prec) = expansion.prec {
+                // like yacc, a `%prec` without a precedence is a mistake rather than no precedence
+                if !declared.contains(&prec.value) {
+                    bail!(
+                        Diagnostic::error(format!("`{}` has no precedence", prec.value))
+                            .label(prec.span, "used by `%prec` here")
+                            .help(format!(
+                                "declare it with `%left {0}`, `%right {0}` or `%nonassoc {0}`",
+                                prec.value
+                            ))
+                    );
+                }
+                prod_builder = prod_builder.prec(prec.value);
+            }
+            let prod = prod_builder.span(expansion.span).finish();
+            builder = builder.production(rule.name.value.to_owned(), prod, expansion.code.value);
         }
     }
 
     let configs = spec.configs;
-    fn find_case_insensitive<'a>(arr: &'a [(String, String)], key: &str) -> Option<&'a str> {
+    type Configs = [(Spanned<String>, Spanned<String>)];
+    fn find_config<'a>(arr: &'a Configs, key: &str) -> Option<&'a Spanned<String>> {
         arr.iter()
-            .find(|x| x.0.to_lowercase() == key.to_lowercase())
-            .map(|x| x.1.as_str())
+            .find(|x| x.0.value.to_lowercase() == key.to_lowercase())
+            .map(|x| &x.1)
     }
 
-    fn required_field(arr: &[(String, String)], key: &str) -> anyhow::Result<String> {
-        find_case_insensitive(arr, key)
-            .ok_or(anyhow!("missing field: {key}"))
-            .map(|x| x.to_owned())
+    fn find_case_insensitive<'a>(arr: &'a Configs, key: &str) -> Option<&'a str> {
+        find_config(arr, key).map(|x| x.value.as_str())
     }
 
-    let entry_point = find_case_insensitive(&configs, "entry")
-        .unwrap_or("ENTRY")
-        .to_owned();
-
-    let token_type = find_case_insensitive(&configs, "type_token")
-        .unwrap_or("token")
-        .to_owned();
+    fn required_field(arr: &Configs, key: &str) -> anyhow::Result<String> {
+        match find_case_insensitive(arr, key) {
+            Some(x) => Ok(x.to_owned()),
+            None => bail!(Diagnostic::error(format!("missing config `{key}`"))),
+        }
+    }
 
-    let language = find_case_insensitive(&configs, "target")
-        .expect("missing target field")
-        .to_owned();
+    let entry = find_config(&configs, "entry");
+    // whether the entry rules are defined is up to `Grammar::analyze`, which points at their names
+    let entry_points = match entry {
+        Some(entry) => entry_points(entry)
+            .into_iter()
+            .map(|x| (x.value, Some(x.span)))
+            .collect(),
+        None => vec![("ENTRY".to_owned(), None)],
+    };
+    if let (Some(entry), true) = (entry, entry_points.is_empty()) {
+        bail!(Diagnostic::error("no entry rule")
+            .label(entry.span, "expected the name of a rule")
+            .help("list the entry rules like `entry = { Prog, Expr }`"));
+    }
 
-    let prelude = find_case_insensitive(&configs, "prelude")
-        .expect("missing prelude field")
-        .to_owned();
+    let prelude = required_field(&configs, "prelude")?;
 
-    let term_types: HashMap<String, String> = configs
-        .iter()
-        .filter_map(|(k, v)| {
-            let (l, r) = k.split_once('_')?;
-            if l.to_lowercase() != "token" {
-                return None;
-            }
+    let reduce_reduce = match find_config(&configs, "reduce_reduce") {
+        None => ReduceReduce::Error,
+        Some(x) if x.value == "error" => ReduceReduce::Error,
+        Some(x) if x.value == "earlier" => ReduceReduce::Earlier,
+        Some(x) => bail!(Diagnostic::error(format!(
+            "invalid value for reduce_reduce: `{}`",
+            x.value
+        ))
+        .label(x.span, "expected `error` or `earlier`")),
+    };
 
-            Some((r.to_owned(), v.to_owned()))
-        })
-        .collect();
+    let grammar = builder
+        .reduce_reduce(reduce_reduce)
+        .finish_with_spanned_entries(entry_points);
 
-    let grammar = builder.finish(entry_point.to_owned());
-
-    let visitor: Box<dyn Frontend> = match language.as_str() {
-        "ocaml" => Box::new(OcamlVisitor::new(
-            prelude,
-            non_term_types,
-            term_types,
-            entry_point,
-        )),
-        "rust" => Box::new(
-            Rust::new(prelude, non_term_types, term_types, entry_point, token_type)
-                .use_default_for_token(),
-        ),
-        "python" => Box::new(Python::new(
-            prelude,
-            entry_point,
-            find_case_insensitive(&configs, "gen_token_fn").map(|x| x.to_owned()),
-            required_field(&configs, "get_data")?,
-            required_field(&configs, "get_kind")?,
-            required_field(&configs, "token_kind")?,
-        )),
-        _ => bail!("unsupported target language: {language}"),
+    let visitor: Box<dyn Frontend> = match target.value.as_str() {
+        "ocaml" => Box::new(OcamlVisitor::new(prelude, non_term_types, term_types)),
+        "rust" => {
+            let mut rust = Rust::new(prelude, non_term_types, term_types, token_type);
+            let merge = find_config(&configs, "merge_location");
+            match (find_config(&configs, "type_location"), merge) {
+                (Some(typ), merge) => {
+                    let merge = merge.map(|x| x.value.trim().to_owned());
+                    rust = rust.locations(typ.value.trim().to_owned(), merge);
+                }
+                (None, Some(merge)) => bail!(Diagnostic::error(
+                    "`merge_location` is set without `type_location`"
+                )
+                .label(merge.span, "the tokens have no locations to merge")
+                .help("add the type of the locations, like `type_location = { std::ops::Range<usize> }`")),
+                (None, None) => {}
+            }
+            match find_config(&configs, "backend") {
+                None => Box::new(rust),
+                Some(x) if x.value == "match" => Box::new(rust),
+                Some(x) if x.value == "table" => Box::new(RustTable::new(rust)),
+                Some(x) => bail!(Diagnostic::error(format!(
+                    "invalid value for backend: `{}`",
+                    x.value
+                ))
+                .label(x.span, "expected `match` or `table`")),
+            }
+        }
+        "python" => {
+            let gen_token_fn = configs
+                .iter()
+                .find(|x| x.0.value.eq_ignore_ascii_case("gen_token_fn"));
+            if let Some((key, _)) = gen_token_fn {
+                bail!(Diagnostic::error("`gen_token_fn` is not supported yet")
+                    .label(key.span, "the python target can't generate tokens")
+                    .help("remove it, `get_data` and `get_kind` read the tokens you pass in"));
+            }
+            Box::new(Python::new(
+                prelude,
+                None,
+                required_field(&configs, "get_data")?,
+                required_field(&configs, "get_kind")?,
+                required_field(&configs, "token_kind")?,
+            ))
+        }
+        _ => unreachable!("unsupported targets are rejected before desugaring"),
     };
 
     Ok((grammar, visitor))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// the rendered error for parsing `input`
+    fn error(input: &str) -> String {
+        let error = parse_string("test.ast", input).err().unwrap();
+        let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+        diagnostic
+            .display(Some(Source::new("test.ast", input)))
+            .to_string()
+    }
+
+    /// the first error [`Grammar::analyze`] reports for the grammar of `input`, rendered
+    fn analysis_error(input: &str) -> String {
+        let (grammar, _) = parse_string("test.ast", input).unwrap();
+        let diagnostics = grammar.analyze();
+        let diagnostic = diagnostics.iter().find(|x| x.is_error()).unwrap();
+        diagnostic
+            .display(Some(Source::new("test.ast", input)))
+            .to_string()
+    }
+
+    #[test]
+    fn lexing_errors_have_locations() {
+        let input = "target = rust\nprelude = {}\n\nExpr: { () }\n  | Num $ Num { () }\n";
+        assert_eq!(
+            error(input),
+            "error: unexpected character\n --> test.ast:5:9\n  |\n5 |   | Num $ Num { () }\n  |         ^"
+        );
+
+        assert!(error("prelude = {\n").contains("test.ast:1:11"));
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_token() {
+        let input = "target = rust\nprelude = {}\n\nExpr: { () }\n  | Num = Num { () }\n";
+        assert_eq!(
+            error(input),
+            "error: unexpected `=`\n --> test.ast:5:9\n  |\n5 |   | Num = Num { () }\n  |         ^ expected one of identifier, code literal, `:`, `%prec`, `?`, `*`, `+`, `(` or `sep_by`"
+        );
+
+        assert!(error("target = rust\nExpr: { () }\n  | Num")
+            .starts_with("error: unexpected end of file\n --> test.ast:3:8\n"));
+    }
+
+    #[test]
+    fn productions_have_spans() {
+        let input = "target = rust\nprelude = {}\nentry = Expr\n\nExpr: { () }\n  | Expr Plus Num { () }\n  | { () }\n";
+        let (grammar, _) = parse_string("test.ast", input).unwrap();
+        let source = Source::new("test.ast", input);
+        let [plus, empty] = [0, 1].map(|i| grammar.entries()[i].span().unwrap());
+        assert_eq!(&input[plus.start..plus.end], "Expr Plus Num { () }");
+        assert_eq!(source.line_col(plus.start), (6, 5));
+        assert_eq!(&input[empty.start..empty.end], "{ () }");
+    }
+
+    #[test]
+    fn conflicts_point_at_productions() {
+        let input = "target = rust\nprelude = {}\nentry = Expr\n\nExpr: { () }\n  | Expr Plus Expr { () }\n  | Num { () }\n";
+        let (grammar, _) = parse_string("test.ast", input).unwrap();
+        let graph = Graph::make(&grammar);
+        let conflicts = Table::from_graph(&graph, &grammar).unwrap_err();
+        let diagnostics = conflicts.diagnostics(&graph, &grammar, &[]);
+        assert_eq!(diagnostics.len(), 1);
+        let labels = &diagnostics[0].labels;
+        assert_eq!(labels.len(), 2);
+        assert!(labels[0].primary && labels[0].message == "reduced on `Plus`");
+        assert!(!labels[1].primary && labels[1].message == "shifts `Plus`");
+        for label in labels {
+            assert_eq!(
+                &input[label.span.start..label.span.end],
+                "Expr Plus Expr { () }"
+            );
+        }
+    }
+
+    #[test]
+    fn missing_configs_are_errors() {
+        let rules = "Expr: { () }\n  | Num { () }\n";
+        let message = |input: &str| parse_string("test.ast", input).err().unwrap().to_string();
+        assert_eq!(
+            message(&format!("prelude = {{}}\nentry = Expr\n{rules}")),
+            "missing config `target`"
+        );
+        assert_eq!(
+            message(&format!("target = rust\nentry = Expr\n{rules}")),
+            "missing config `prelude`"
+        );
+        assert_eq!(
+            analysis_error(&format!("target = rust\nprelude = {{}}\n{rules}")),
+            "error: entry rule `ENTRY` is not defined"
+        );
+        assert_eq!(
+            analysis_error(&format!("target = rust\nprelude = {{}}\nentry = Exp\n{rules}")),
+            "error: entry rule `Exp` is not defined\n --> test.ast:3:9\n  |\n3 | entry = Exp\n  |         ^^^ not a rule\n  = help: a rule with a similar name exists: `Expr`"
+        );
+    }
+
+    #[test]
+    fn several_entry_rules() {
+        let rules =
+            "Prog: { () }\n  | Prog Expr Semi { () }\n  | { () }\nExpr: { () }\n  | Num { () }\n";
+        let input = format!("target = rust\nprelude = {{}}\nentry = {{ Prog, Expr }}\n{rules}");
+        let (grammar, _) = parse_string("test.ast", &input).unwrap();
+        let entries: Vec<_> = grammar
+            .entry_rules()
+            .map(|x| grammar.pool().get(x))
+            .collect();
+        assert_eq!(entries, ["Prog", "Expr"]);
+
+        assert_eq!(
+            analysis_error(&format!(
+                "target = rust\nprelude = {{}}\nentry = {{ Prog Exp }}\n{rules}"
+            )),
+            "error: entry rule `Exp` is not defined\n --> test.ast:3:16\n  |\n3 | entry = { Prog Exp }\n  |                ^^^ not a rule\n  = help: a rule with a similar name exists: `Expr`"
+        );
+
+        // separators that take more than one byte
+        let input = format!("target = rust\nprelude = {{}}\nentry = {{ Prog,\u{a0}Exp }}\n{rules}");
+        let (grammar, _) = parse_string("test.ast", &input).unwrap();
+        let diagnostic = grammar
+            .analyze()
+            .into_iter()
+            .find(|x| x.is_error())
+            .unwrap();
+        let span = diagnostic.labels[0].span;
+        assert_eq!(&input[span.start..span.end], "Exp");
+    }
+
+    #[test]
+    fn rules_may_be_called_s0() {
+        let input = "target = rust\nprelude = {}\nentry = S0\n\nS0: { () }\n  | S0 Plus Num { () }\n  | Num { () }\n";
+        let (grammar, visitor) = parse_string("test.ast", input).unwrap();
+        let graph = Graph::make(&grammar);
+        let table = Table::from_graph(&graph, &grammar).unwrap();
+        let rendered = Render::new(&visitor, &table, &grammar).unwrap().to_string();
+        assert!(rendered.contains("NonTerm::S0_"));
+    }
+
+    #[test]
+    fn locations_are_opt_in() {
+        let rules = "entry = Expr\n\nExpr: { () }\n  | lhs:Expr Plus Num { f(@lhs, @2, @$, a@b, \"@0\", '@') }\n  | Num { () }\n";
+        let render = |configs: &str| {
+            let input =
+                format!("target = rust\nprelude = {{}}\ntype_token = Token\n{configs}{rules}");
+            let (grammar, visitor) = parse_string("test.ast", &input).unwrap();
+            let graph = Graph::make(&grammar);
+            let table = Table::from_graph(&graph, &grammar).unwrap();
+            Render::new(&visitor, &table, &grammar).unwrap().to_string()
+        };
+
+        let rendered = render("type_location = Span\nmerge_location = merge\n");
+        assert!(rendered.contains("f(__loc0, __loc2, __loc, a@b, \"@0\", '@')"));
+        assert!(rendered.contains("Iterator<Item = (Token, Span)>"));
+        assert!(rendered.contains("merge(first, last)"));
+
+        let rendered = render("");
+        assert!(rendered.contains("f(@lhs, @2, @$, a@b, \"@0\", '@')"));
+        assert!(rendered.contains("Iterator<Item = Token>"));
+
+        assert_eq!(
+            error(&format!("target = rust\nprelude = {{}}\nmerge_location = merge\n{rules}")),
+            "error: `merge_location` is set without `type_location`\n --> test.ast:3:18\n  |\n3 | merge_location = merge\n  |                  ^^^^^ the tokens have no locations to merge\n  = help: add the type of the locations, like `type_location = { std::ops::Range<usize> }`"
+        );
+    }
+
+    #[test]
+    fn prec_without_precedence() {
+        let rules = "Expr: { () }\n  | Expr Minus Expr { () }\n  | Minus Expr %prec UMINUS { () }\n  | Num { () }\n";
+        assert_eq!(
+            error(&format!("target = rust\nprelude = {{}}\nentry = Expr\n%left Minus\n\n{rules}")),
+            "error: `UMINUS` has no precedence\n --> test.ast:8:22\n  |\n8 |   | Minus Expr %prec UMINUS { () }\n  |                      ^^^^^^ used by `%prec` here\n  = help: declare it with `%left UMINUS`, `%right UMINUS` or `%nonassoc UMINUS`"
+        );
+        let input = format!(
+            "target = rust\nprelude = {{}}\nentry = Expr\n%left Minus\n%right UMINUS\n\n{rules}"
+        );
+        assert!(parse_string("test.ast", &input).is_ok());
+    }
+
+    #[test]
+    fn generated_tokens_are_rejected() {
+        let input = "target = python\nprelude = {}\nentry = Expr\nget_data = { x }\nget_kind = { x }\ntoken_kind = Kind\ngen_token_fn = gen\n\nExpr: { () }\n  | Num { () }\n";
+        assert_eq!(
+            error(input),
+            "error: `gen_token_fn` is not supported yet\n --> test.ast:7:1\n  |\n7 | gen_token_fn = gen\n  | ^^^^^^^^^^^^ the python target can't generate tokens\n  = help: remove it, `get_data` and `get_kind` read the tokens you pass in"
+        );
+    }
+}
@@ -1,18 +1,172 @@
+use crate::{
+    grammar::Assoc,
+    source::{Span, Spanned},
+};
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Spec {
     pub(super) rules: Vec<Rule>,
-    pub(super) configs: Vec<(String, String)>,
+    pub(super) configs: Vec<(Spanned<String>, Spanned<String>)>,
+    pub(super) precedence: Vec<(Assoc, Vec<Spanned<String>>)>,
+}
+
+impl Spec {
+    pub(super) fn new(configs: Vec<Config>, rules: Vec<Rule>) -> Self {
+        let mut spec = Spec {
+            rules,
+            configs: Vec::new(),
+            precedence: Vec::new(),
+        };
+        for config in configs {
+            match config {
+                Config::Pair(k, v) => spec.configs.push((k, v)),
+                Config::Precedence(assoc, terms) => spec.precedence.push((assoc, terms)),
+            }
+        }
+        spec
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Config {
+    Pair(Spanned<String>, Spanned<String>),
+    Precedence(Assoc, Vec<Spanned<String>>),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Rule {
-    pub(super) name: String,
-    pub(super) typ: String,
+    pub(super) name: Spanned<String>,
+    pub(super) typ: Spanned<String>,
     pub(super) expansions: Vec<Expansion>,
+    /// from the name of the rule to the end of its last expansion
+    pub(super) span: Span,
+}
+
+impl Rule {
+    /// `expansions` are expected back to front, the way the right recursive `CaseList` collects
+    /// them
+    pub(super) fn new(
+        name: Spanned<String>,
+        typ: Spanned<String>,
+        mut expansions: Vec<Expansion>,
+    ) -> Self {
+        expansions.reverse();
+        let end = expansions.last().map_or(typ.span, |x| x.span);
+        Rule {
+            span: name.span.to(end),
+            name,
+            typ,
+            expansions,
+        }
+    }
+}
+
+/// A symbol of an expansion, or one of the shorthands that stand for a fresh rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Item {
+    /// a rule or a terminal
+    Symbol(String),
+    /// `X?`, either `X` or nothing
+    Optional(Box<Spanned<Item>>),
+    /// `X*`, any number of `X`
+    Star(Box<Spanned<Item>>),
+    /// `X+`, at least one `X`
+    Plus(Box<Spanned<Item>>),
+    /// `(A B)`, all of the items in a row
+    Group(Vec<Spanned<Item>>),
+    /// `sep_by(X, Comma)`, any number of `X` with a `Comma` between each two of them
+    SepBy(Box<Spanned<Item>>, Box<Spanned<Item>>),
+}
+
+impl Item {
+    pub(super) fn symbol(name: Spanned<String>) -> Spanned<Item> {
+        Spanned::new(Item::Symbol(name.value), name.span)
+    }
+
+    /// `X?`, `X*` or `X+`, depending on the operator that ends at `end`
+    pub(super) fn repeat(
+        item: Spanned<Item>,
+        op: fn(Box<Spanned<Item>>) -> Item,
+        end: Span,
+    ) -> Spanned<Item> {
+        let span = item.span.to(end);
+        Spanned::new(op(Box::new(item)), span)
+    }
+
+    pub(super) fn group(open: Span, items: Vec<Spanned<Item>>, close: Span) -> Spanned<Item> {
+        Spanned::new(Item::Group(items), open.to(close))
+    }
+
+    pub(super) fn sep_by(
+        start: Span,
+        item: Spanned<Item>,
+        separator: Spanned<Item>,
+        close: Span,
+    ) -> Spanned<Item> {
+        Spanned::new(
+            Item::SepBy(Box::new(item), Box::new(separator)),
+            start.to(close),
+        )
+    }
+}
+
+impl Spanned<Item> {
+    /// the rules and terminals this item refers to, in the order they appear
+    pub(super) fn symbols(&self) -> Vec<Spanned<&str>> {
+        match &self.value {
+            Item::Symbol(name) => vec![Spanned::new(name.as_str(), self.span)],
+            Item::Optional(x) | Item::Star(x) | Item::Plus(x) => x.symbols(),
+            Item::Group(items) => items.iter().flat_map(|x| x.symbols()).collect(),
+            Item::SepBy(item, separator) => {
+                let mut symbols = item.symbols();
+                symbols.extend(separator.symbols());
+                symbols
+            }
+        }
+    }
+}
+
+/// An item of an expansion, optionally bound to a name like `lhs:Expr` that the code can use
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Binding {
+    pub(super) name: Option<Spanned<String>>,
+    pub(super) item: Spanned<Item>,
+}
+
+impl Binding {
+    pub(super) fn new(name: Option<Spanned<String>>, item: Spanned<Item>) -> Self {
+        Binding { name, item }
+    }
+
+    /// from the name, if any, to the end of the item
+    pub(super) fn span(&self) -> Span {
+        self.name
+            .as_ref()
+            .map_or(self.item.span, |x| x.span.to(self.item.span))
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Expansion {
-    pub(super) tokens: Vec<String>,
-    pub(super) code: String,
+    pub(super) items: Vec<Binding>,
+    pub(super) code: Spanned<String>,
+    pub(super) prec: Option<Spanned<String>>,
+    /// from the first symbol, or the code if there is none, to the end of the code
+    pub(super) span: Span,
+}
+
+impl Expansion {
+    pub(super) fn new(
+        items: Vec<Binding>,
+        code: Spanned<String>,
+        prec: Option<Spanned<String>>,
+    ) -> Self {
+        let start = items.first().map_or(code.span, |x| x.span());
+        Expansion {
+            span: start.to(code.span),
+            items,
+            code,
+            prec,
+        }
+    }
 }
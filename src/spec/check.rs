@@ -0,0 +1,668 @@
+//! Checks for likely mistakes in a spec that still make a valid grammar
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::bail;
+
+use crate::{
+    diagnostics::{suggest, Diagnostic},
+    frontends::rust::location_refs,
+    source::{Span, Spanned},
+};
+
+use super::ast::Spec;
+
+/// the config keys every target understands
+const COMMON_KEYS: &[&str] = &["target", "prelude", "entry", "reduce_reduce"];
+
+/// The config keys each target understands on top of the common ones. A trailing `*` stands for
+/// any name, `token_*` declares the data of the token `*`.
+const TARGET_KEYS: &[(&str, &[&str])] = &[
+    (
+        "rust",
+        &[
+            "type_token",
+            "token_*",
+            "backend",
+            "type_location",
+            "merge_location",
+        ],
+    ),
+    ("ocaml", &["token_*"]),
+    (
+        "python",
+        &["gen_token_fn", "get_data", "get_kind", "token_kind"],
+    ),
+];
+
+/// The names the code generated for each target uses next to the values of a production, with the
+/// same patterns as [`TARGET_KEYS`]. Binding a value to one of them would shadow it.
+const GENERATED_NAMES: &[(&str, &[&str])] = &[
+    (
+        "rust",
+        &["stack", "values", "locations", "value", "__loc", "__loc*"],
+    ),
+    ("ocaml", &["_stack"]),
+    ("python", &["stack", "expected_type"]),
+];
+
+/// the keywords of each target, which can't be bound at all
+const KEYWORDS: &[(&str, &[&str])] = &[
+    (
+        "rust",
+        &[
+            "_", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+            "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if",
+            "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override",
+            "priv", "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait",
+            "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual", "where",
+            "while", "yield",
+        ],
+    ),
+    (
+        "ocaml",
+        &[
+            "_",
+            "and",
+            "as",
+            "asr",
+            "assert",
+            "begin",
+            "class",
+            "constraint",
+            "do",
+            "done",
+            "downto",
+            "else",
+            "end",
+            "exception",
+            "external",
+            "false",
+            "for",
+            "fun",
+            "function",
+            "functor",
+            "if",
+            "in",
+            "include",
+            "inherit",
+            "initializer",
+            "land",
+            "lazy",
+            "let",
+            "lor",
+            "lsl",
+            "lsr",
+            "lxor",
+            "match",
+            "method",
+            "mod",
+            "module",
+            "mutable",
+            "new",
+            "nonrec",
+            "object",
+            "of",
+            "open",
+            "or",
+            "private",
+            "rec",
+            "sig",
+            "struct",
+            "then",
+            "to",
+            "true",
+            "try",
+            "type",
+            "val",
+            "virtual",
+            "when",
+            "while",
+            "with",
+        ],
+    ),
+    (
+        "python",
+        &[
+            "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class",
+            "continue", "def", "del", "elif", "else", "except", "finally", "for", "from", "global",
+            "if", "import", "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise",
+            "return", "try", "while", "with", "yield",
+        ],
+    ),
+];
+
+/// The key a config is looked up by. Keys are case insensitive, except for the token names of
+/// `token_*` configs.
+fn normalize(key: &str) -> String {
+    match key.split_once('_') {
+        Some((prefix, name)) if prefix.eq_ignore_ascii_case("token") && !name.is_empty() => {
+            format!("token_{name}")
+        }
+        _ => key.to_lowercase(),
+    }
+}
+
+/// whether `key` is one of `keys`, expanding the patterns that end in `*`
+fn matches_any(keys: &[&str], key: &str) -> bool {
+    keys.iter().any(|x| match x.strip_suffix('*') {
+        Some(prefix) => key.len() > prefix.len() && key.starts_with(prefix),
+        None => key == *x,
+    })
+}
+
+/// Warns about configs that are set more than once, and about keys `target` doesn't understand,
+/// suggesting the known key they are closest to. Without a `target`, keys of any target are
+/// accepted.
+pub(super) fn check_configs(
+    configs: &[(Spanned<String>, Spanned<String>)],
+    target: Option<&str>,
+) -> Vec<Diagnostic> {
+    let target_keys = TARGET_KEYS.iter().find(|x| Some(x.0) == target);
+    let mut known: Vec<&str> = COMMON_KEYS.to_vec();
+    match target_keys {
+        Some((_, keys)) => known.extend(keys.iter()),
+        None => known.extend(TARGET_KEYS.iter().flat_map(|x| x.1.iter())),
+    }
+
+    let mut diagnostics = Vec::new();
+    let mut first_set = HashMap::new();
+    for (key, _) in configs {
+        let normalized = normalize(&key.value);
+        if let Some(&first) = first_set.get(&normalized) {
+            diagnostics.push(
+                Diagnostic::warning(format!("config `{}` is set more than once", key.value))
+                    .label(key.span, "set again here")
+                    .secondary(first, "first set here")
+                    .note("only the first value is used"),
+            );
+            continue;
+        }
+        first_set.insert(normalized.clone(), key.span);
+
+        if matches_any(&known, &normalized) {
+            continue;
+        }
+        let mut diagnostic = Diagnostic::warning(format!("unknown config `{}`", key.value));
+        let others = TARGET_KEYS
+            .iter()
+            .filter(|x| matches_any(x.1, &normalized))
+            .map(|x| format!("`{}`", x.0))
+            .collect::<Vec<_>>();
+        if let (false, Some(target)) = (others.is_empty(), target) {
+            diagnostic = diagnostic.label(
+                key.span,
+                format!(
+                    "not used by the `{target}` target, only by {}",
+                    others.join(", ")
+                ),
+            );
+        } else {
+            diagnostic = diagnostic.label(key.span, "this config is ignored");
+        }
+
+        // `token_*` patterns are completed with the name of the misspelled key
+        let name = key.value.split_once('_').map_or("", |x| x.1);
+        let candidates: Vec<String> = known
+            .iter()
+            .map(|x| match x.strip_suffix('*') {
+                Some(prefix) => format!("{prefix}{name}"),
+                None => x.to_string(),
+            })
+            .collect();
+        let lowercase: Vec<String> = candidates.iter().map(|x| x.to_lowercase()).collect();
+        let lower_key = key.value.to_lowercase();
+        if let Some(found) = suggest(&lower_key, lowercase.iter().map(|x| x.as_str())) {
+            let key = &candidates[lowercase.iter().position(|x| x == found).unwrap()];
+            diagnostic = diagnostic.help(format!("a config with a similar name exists: `{key}`"));
+        }
+        diagnostics.push(diagnostic);
+    }
+    diagnostics
+}
+
+/// Every identifier in an expansion that is not a rule becomes a terminal, so a misspelled rule
+/// silently turns into a token the lexer never produces. This warns about terminals that are
+/// close to the name of a rule, and about terminals that are not declared anywhere. Terminals
+/// are declared by a `token_*` config or, if the prelude defines the token type, by its variants.
+pub(super) fn check_terminals(spec: &Spec, token_type: &str, prelude: &str) -> Vec<Diagnostic> {
+    let rules: HashSet<&str> = spec.rules.iter().map(|x| x.name.value.as_str()).collect();
+    let mut declared: HashSet<&str> = spec
+        .configs
+        .iter()
+        .filter_map(|(k, _)| {
+            let (prefix, name) = k.value.split_once('_')?;
+            prefix.eq_ignore_ascii_case("token").then_some(name)
+        })
+        .collect();
+    let variants = token_variants(prelude, token_type);
+    if let Some(variants) = &variants {
+        declared.extend(variants.iter().map(|x| x.as_str()));
+    }
+
+    let mut seen = HashSet::new();
+    let mut diagnostics = Vec::new();
+    let tokens = spec
+        .rules
+        .iter()
+        .flat_map(|x| &x.expansions)
+        .flat_map(|x| &x.items)
+        .flat_map(|x| x.item.symbols());
+    for token in tokens {
+        let name = token.value;
+        if rules.contains(name) || declared.contains(name) || !seen.insert(name) {
+            continue;
+        }
+
+        // rules and terminals that only differ in case are usually a naming convention
+        let candidates = rules
+            .iter()
+            .copied()
+            .filter(|x| !x.eq_ignore_ascii_case(name));
+        if let Some(rule) = suggest(name, candidates) {
+            diagnostics.push(
+                Diagnostic::warning(format!(
+                    "`{name}` is not a rule, so it is used as a terminal"
+                ))
+                .label(token.span, "used as a terminal here")
+                .help(format!("a rule with a similar name exists: `{rule}`")),
+            );
+        } else if variants.is_some() {
+            diagnostics.push(
+                Diagnostic::warning(format!("terminal `{name}` is never declared"))
+                    .label(token.span, format!("not a variant of `{token_type}`"))
+                    .help(format!(
+                        "add it to `{token_type}` in the prelude, or declare its data with `token_{name}`"
+                    )),
+            );
+        }
+    }
+    diagnostics
+}
+
+/// Names bound in an expansion have to be unique, must not look like the positional `vN` and must
+/// not be a keyword of `target` or a name its generated code uses
+pub(super) fn check_bindings(spec: &Spec, target: &str) -> anyhow::Result<()> {
+    let names_of = |table: &[(&str, &'static [&'static str])]| {
+        table
+            .iter()
+            .find(|x| x.0 == target)
+            .map_or(&[][..], |x| x.1)
+    };
+    let (generated, keywords) = (names_of(GENERATED_NAMES), names_of(KEYWORDS));
+    for expansion in spec.rules.iter().flat_map(|x| &x.expansions) {
+        let mut bound: HashMap<&str, Span> = HashMap::new();
+        for name in expansion.items.iter().filter_map(|x| x.name.as_ref()) {
+            let reserved = if position(&name.value).is_some() {
+                Some((
+                    "reserved for the value at that position".to_owned(),
+                    "choose a name that is not of the form `v0`, `v1`, ...",
+                ))
+            } else if keywords.contains(&name.value.as_str()) {
+                Some((format!("a keyword in {target}"), "choose another name"))
+            } else if matches_any(generated, &name.value) {
+                Some((
+                    "used by the generated parser".to_owned(),
+                    "choose another name",
+                ))
+            } else {
+                None
+            };
+            if let Some((label, help)) = reserved {
+                bail!(
+                    Diagnostic::error(format!("`{}` can't be used as a name", name.value))
+                        .label(name.span, label)
+                        .help(help)
+                );
+            }
+            if let Some(&first) = bound.get(name.value.as_str()) {
+                bail!(Diagnostic::error(format!(
+                    "`{}` is bound more than once in the same production",
+                    name.value
+                ))
+                .label(name.span, "bound again here")
+                .secondary(first, "first bound here"));
+            }
+            bound.insert(&name.value, name.span);
+        }
+    }
+    Ok(())
+}
+
+/// Warns about code that uses a `vN` past the end of its production, which usually means a
+/// symbol was removed or the code was copied from another production.
+pub(super) fn check_positions(spec: &Spec) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    for expansion in spec.rules.iter().flat_map(|x| &x.expansions) {
+        let len = expansion.items.len();
+        // the value of a code literal starts after its `{`
+        let start = expansion.code.span.start + 1;
+        for (offset, word) in identifiers(&expansion.code.value) {
+            if position(word).is_none_or(|x| x < len) {
+                continue;
+            }
+            let span = Span::new(start + offset, start + offset + word.len());
+            let label = match len {
+                0 => "the production has no symbols".to_owned(),
+                1 => "the production only has 1 symbol".to_owned(),
+                n => format!("the production only has {n} symbols"),
+            };
+            diagnostics.push(
+                Diagnostic::warning(format!("`{word}` is out of range"))
+                    .label(span, label)
+                    .note("values are numbered from `v0`"),
+            );
+        }
+    }
+    diagnostics
+}
+
+/// Warns about `@N` and `@name` in the code of the rust target that refer to no value of their
+/// production. They are left in the code as they are, where rustc would point at the generated
+/// parser instead of the spec.
+pub(super) fn check_locations(spec: &Spec) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    for expansion in spec.rules.iter().flat_map(|x| &x.expansions) {
+        let len = expansion.items.len();
+        let start = expansion.code.span.start + 1;
+        for (at, word) in location_refs(&expansion.code.value) {
+            let label = match word.parse::<usize>() {
+                _ if word == "$" => continue,
+                Ok(i) if i < len => continue,
+                Ok(_) => match len {
+                    0 => "the production has no symbols".to_owned(),
+                    1 => "the production only has 1 symbol".to_owned(),
+                    n => format!("the production only has {n} symbols"),
+                },
+                Err(_) => {
+                    let mut names = expansion.items.iter().filter_map(|x| x.name.as_ref());
+                    if names.any(|x| x.value == word) {
+                        continue;
+                    }
+                    format!("no value is named `{word}`")
+                }
+            };
+            let span = Span::new(start + at, start + at + 1 + word.len());
+            diagnostics.push(
+                Diagnostic::warning(format!("`@{word}` refers to no value"))
+                    .label(span, label)
+                    .note("locations are `@$`, `@0`, `@1`, ... or `@` and the name of a value"),
+            );
+        }
+    }
+    diagnostics
+}
+
+/// `n` for `vn`
+fn position(name: &str) -> Option<usize> {
+    let digits = name.strip_prefix('v')?;
+    match digits.bytes().all(|x| x.is_ascii_digit()) {
+        true => digits.parse().ok(),
+        false => None,
+    }
+}
+
+/// the identifiers in `code` and their offsets, skipping fields like `x.v0`
+fn identifiers(code: &str) -> impl Iterator<Item = (usize, &str)> {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    let mut words = Vec::new();
+    let mut start = None;
+    for (i, c) in code.char_indices().chain([(code.len(), ' ')]) {
+        match (start, is_ident(c)) {
+            (None, true) => start = Some(i),
+            (Some(s), false) => {
+                if !code[..s].ends_with('.') {
+                    words.push((s, &code[s..i]));
+                }
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    words.into_iter()
+}
+
+/// The variants of `enum <token_type>` if the prelude defines it, like
+///
+/// ```text
+/// pub enum Token {
+///     Plus,
+///     Int(i32),
+/// }
+/// ```
+fn token_variants(prelude: &str, token_type: &str) -> Option<HashSet<String>> {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    let ident_len = |s: &str| s.find(|c| !is_ident(c)).unwrap_or(s.len());
+    let rest = prelude.match_indices("enum").find_map(|(i, _)| {
+        let before = prelude[..i].chars().next_back();
+        let rest = prelude[i + "enum".len()..].trim_start();
+        let name = &rest[..ident_len(rest)];
+        (!before.is_some_and(is_ident) && name == token_type).then_some(&rest[name.len()..])
+    })?;
+    let body: String = rest[rest.find('{')? + 1..]
+        .lines()
+        .map(|x| x.split_once("//").map_or(x, |x| x.0))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    // split the body into variants at the top level commas
+    let mut variants = HashSet::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in body.char_indices() {
+        match c {
+            '(' | '{' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            '}' if depth > 0 => depth -= 1,
+            ',' | '}' if depth == 0 => {
+                let mut variant = body[start..i].trim_start();
+                // skip attributes
+                while variant.starts_with("#[") {
+                    variant =
+                        variant[variant.find(']').map_or(variant.len(), |x| x + 1)..].trim_start();
+                }
+                let name = &variant[..ident_len(variant)];
+                if !name.is_empty() {
+                    variants.insert(name.to_owned());
+                }
+                if c == '}' {
+                    break;
+                }
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    Some(variants)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::{Source, Span};
+
+    fn check(input: &str) -> Vec<Diagnostic> {
+        let tokens = super::super::lex(Source::new("test.ast", input)).unwrap();
+        let spec = super::super::parser::parse(tokens.into_iter().map(|x| x.0)).unwrap();
+        check_terminals(&spec, "Token", "pub enum Token { Plus, Num(i32) }")
+    }
+
+    #[test]
+    fn misspelled_rules() {
+        let input = "EXPR_ADD: { () }\n  | EXPR_MUL Plus EXPR_MLU { () }\n  | EXPR_MUL { () }\nEXPR_MUL: { () }\n  | Num { () }\n";
+        let diagnostics = check(input);
+        assert_eq!(diagnostics.len(), 1);
+        let span = diagnostics[0].labels[0].span;
+        assert_eq!(&input[span.start..span.end], "EXPR_MLU");
+        assert_eq!(
+            diagnostics[0].help,
+            ["a rule with a similar name exists: `EXPR_MUL`"]
+        );
+    }
+
+    #[test]
+    fn undeclared_terminals() {
+        let input =
+            "Expr: { () }\n  | Expr Plus Num { () }\n  | Expr Minus Num { () }\n  | Num { () }\n";
+        let diagnostics = check(input);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "terminal `Minus` is never declared");
+    }
+
+    fn check_config(input: &str, target: Option<&str>) -> Vec<Diagnostic> {
+        let tokens = super::super::lex(Source::new("test.ast", input)).unwrap();
+        let spec = super::super::parser::parse(tokens.into_iter().map(|x| x.0)).unwrap();
+        check_configs(&spec.configs, target)
+    }
+
+    #[test]
+    fn unknown_configs() {
+        let input = "entyr = Expr\ntokn_Int = i32\nToken_Num = i32\nget_kind = kind\nfoo = bar\n";
+        let diagnostics = check_config(input, Some("rust"));
+        let messages: Vec<_> = diagnostics.iter().map(|x| x.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                "unknown config `entyr`",
+                "unknown config `tokn_Int`",
+                "unknown config `get_kind`",
+                "unknown config `foo`"
+            ]
+        );
+        assert_eq!(
+            diagnostics[0].help,
+            ["a config with a similar name exists: `entry`"]
+        );
+        assert_eq!(
+            diagnostics[1].help,
+            ["a config with a similar name exists: `token_Int`"]
+        );
+        assert_eq!(
+            diagnostics[2].labels[0].message,
+            "not used by the `rust` target, only by `python`"
+        );
+        assert!(diagnostics[3].help.is_empty());
+
+        // without a target, the keys of all targets are fine
+        assert_eq!(check_config(input, None).len(), 3);
+    }
+
+    #[test]
+    fn duplicate_configs() {
+        let input = "entry = Expr\nENTRY = Term\ntoken_Int = i32\ntoken_int = i64\n";
+        let diagnostics = check_config(input, Some("rust"));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "config `ENTRY` is set more than once"
+        );
+        let spans: Vec<_> = diagnostics[0].labels.iter().map(|x| x.span).collect();
+        assert_eq!(spans, [Span::new(13, 18), Span::new(0, 5)]);
+    }
+
+    fn parse(input: &str) -> Spec {
+        let tokens = super::super::lex(Source::new("test.ast", input)).unwrap();
+        super::super::parser::parse(tokens.into_iter().map(|x| x.0)).unwrap()
+    }
+
+    #[test]
+    fn out_of_range_positions() {
+        let input = "Expr: { i32 }\n  | lhs:Expr Plus Num { lhs + v2 + x.v3 + v3 }\n  | { v0 }\n";
+        let diagnostics = check_positions(&parse(input));
+        let messages: Vec<_> = diagnostics.iter().map(|x| x.message.as_str()).collect();
+        assert_eq!(messages, ["`v3` is out of range", "`v0` is out of range"]);
+        let span = diagnostics[0].labels[0].span;
+        assert_eq!(&input[span.start..span.end], "v3");
+        assert_eq!(
+            diagnostics[1].labels[0].message,
+            "the production has no symbols"
+        );
+    }
+
+    #[test]
+    fn unresolved_locations() {
+        let input = "Expr: { () }\n  | lhs:Expr Plus Num { f(@lhs, @2, @$, @3, @rhs, x @ Some(_), \"@4\", '@', r#\"@5\"#) }\n  | { g(@0, 'a, b'@', @$) }\n";
+        let diagnostics = check_locations(&parse(input));
+        let messages: Vec<_> = diagnostics.iter().map(|x| x.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                "`@3` refers to no value",
+                "`@rhs` refers to no value",
+                "`@0` refers to no value"
+            ]
+        );
+        let span = diagnostics[1].labels[0].span;
+        assert_eq!(&input[span.start..span.end], "@rhs");
+        assert_eq!(diagnostics[1].labels[0].message, "no value is named `rhs`");
+        assert_eq!(
+            diagnostics[2].labels[0].message,
+            "the production has no symbols"
+        );
+    }
+
+    #[test]
+    fn invalid_bindings() {
+        let error = |input: &str, target: &str| {
+            let error = check_bindings(&parse(input), target).unwrap_err();
+            let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+            (
+                diagnostic.message.clone(),
+                diagnostic.labels[0].message.clone(),
+            )
+        };
+        let message = |input: &str| error(input, "rust").0;
+        assert!(check_bindings(&parse("Expr: { () }\n  | x:Num y:Num { () }\n"), "rust").is_ok());
+        assert_eq!(
+            message("Expr: { () }\n  | x:Num x:Num { () }\n"),
+            "`x` is bound more than once in the same production"
+        );
+        assert_eq!(
+            message("Expr: { () }\n  | Num v0:Num { () }\n"),
+            "`v0` can't be used as a name"
+        );
+
+        // the names the generated code uses and keywords depend on the target
+        let input = "Expr: { () }\n  | Num stack:Num { () }\n";
+        for target in ["rust", "python"] {
+            assert_eq!(
+                error(input, target),
+                (
+                    "`stack` can't be used as a name".to_owned(),
+                    "used by the generated parser".to_owned()
+                )
+            );
+        }
+        assert!(check_bindings(&parse(input), "ocaml").is_ok());
+        assert_eq!(
+            message("Expr: { () }\n  | __loc1:Num { () }\n"),
+            "`__loc1` can't be used as a name"
+        );
+        assert_eq!(
+            error("Expr: { () }\n  | lambda:Num { () }\n", "python").1,
+            "a keyword in python"
+        );
+        assert!(check_bindings(&parse("Expr: { () }\n  | lambda:Num { () }\n"), "rust").is_ok());
+    }
+
+    #[test]
+    fn token_variants_of_the_prelude() {
+        let prelude = "
+            use std::fmt;
+            /// the tokens
+            #[derive(Debug, Clone)]
+            pub enum Token {
+                Plus, // `+`
+                #[allow(unused)]
+                Int(i32),
+                Call { name: String, args: Vec<(i32, i32)> },
+                Eof
+            }";
+        let variants = token_variants(prelude, "Token").unwrap();
+        let mut variants: Vec<_> = variants.iter().map(|x| x.as_str()).collect();
+        variants.sort();
+        assert_eq!(variants, ["Call", "Eof", "Int", "Plus"]);
+        assert_eq!(token_variants(prelude, "Tok"), None);
+        assert_eq!(token_variants("use crate::Token;", "Token"), None);
+    }
+}
@@ -1,6 +1,35 @@
+use std::fmt::Display;
+
 use logos::{Lexer, Logos};
 
-fn parse_literal(lex: &mut Lexer<Token>) -> Option<String> {
+use crate::source::{Span, Spanned};
+
+use super::parser::{kind, TokenKind};
+
+/// The ways lexing a grammar file can fail
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum LexError {
+    #[default]
+    UnexpectedCharacter,
+    /// a `{` without the matching `}`
+    UnterminatedLiteral,
+    /// a precedence directive listing something that is not a terminal
+    InvalidTerminal(String),
+}
+
+impl Display for LexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LexError::UnexpectedCharacter => write!(f, "unexpected character"),
+            LexError::UnterminatedLiteral => {
+                write!(f, "unterminated literal, `{{` is never closed")
+            }
+            LexError::InvalidTerminal(term) => write!(f, "`{term}` is not a valid terminal name"),
+        }
+    }
+}
+
+fn parse_literal(lex: &mut Lexer<Token>) -> Result<Spanned<String>, LexError> {
     let remainder = lex.remainder();
     let mut depth = 1;
     for (i, c) in remainder.char_indices() {
@@ -10,18 +39,40 @@ fn parse_literal(lex: &mut Lexer<Token>) -> Option<String> {
                 depth -= 1;
                 if depth <= 0 {
                     lex.bump(i + 1);
-                    return Some(remainder[..i].to_owned());
+                    return Ok(Spanned::new(remainder[..i].to_owned(), lex.span()));
                 }
             }
             _ => {}
         }
     }
-    None
+    Err(LexError::UnterminatedLiteral)
+}
+
+/// parses the terminals following a precedence directive up to the end of the line
+fn parse_precedence(lex: &mut Lexer<Token>) -> Result<Vec<Spanned<String>>, LexError> {
+    let mut terms = Vec::new();
+    let mut offset = lex.span().start;
+    // the first word is the directive itself. all separators are a single byte long
+    for (i, word) in lex
+        .slice()
+        .split(|c: char| c.is_ascii_whitespace())
+        .enumerate()
+    {
+        if i > 0 && !word.is_empty() {
+            if !word.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                return Err(LexError::InvalidTerminal(word.to_owned()));
+            }
+            terms.push(Spanned::new(word.to_owned(), offset..offset + word.len()));
+        }
+        offset += word.len() + 1;
+    }
+    Ok(terms)
 }
 
 #[derive(Logos, Debug, PartialEq, Eq, Clone)]
 #[logos(skip r"[ \t\n\f]+")]
-#[logos(skip r"#[^\n]*\n")]
+#[logos(skip r"#[^\n]*")]
+#[logos(error = LexError)]
 pub enum Token {
     #[token("=")]
     Equals,
@@ -32,9 +83,78 @@ pub enum Token {
     #[token("|")]
     Pipe,
 
-    #[regex("[a-zA-Z_][a-zA-Z_0-9]+", |l| Some(l.slice().to_owned()))]
-    Ident(String),
+    #[regex("[a-zA-Z_][a-zA-Z_0-9]*", |l| Spanned::new(l.slice().to_owned(), l.span()))]
+    Ident(Spanned<String>),
 
     #[token("{", parse_literal)]
-    Literal(String),
+    Literal(Spanned<String>),
+
+    /// `%left` followed by the terminals of this precedence level
+    #[regex(r"%left[ \t\f][^\n#]*", parse_precedence)]
+    Left(Vec<Spanned<String>>),
+
+    /// `%right` followed by the terminals of this precedence level
+    #[regex(r"%right[ \t\f][^\n#]*", parse_precedence)]
+    Right(Vec<Spanned<String>>),
+
+    /// `%nonassoc` followed by the terminals of this precedence level
+    #[regex(r"%nonassoc[ \t\f][^\n#]*", parse_precedence)]
+    NonAssoc(Vec<Spanned<String>>),
+
+    #[token("%prec")]
+    Prec,
+
+    #[token("?", |l| Span::from(l.span()))]
+    Question(Span),
+
+    #[token("*", |l| Span::from(l.span()))]
+    Star(Span),
+
+    #[token("+", |l| Span::from(l.span()))]
+    Plus(Span),
+
+    #[token("(", |l| Span::from(l.span()))]
+    OpenParen(Span),
+
+    #[token(")", |l| Span::from(l.span()))]
+    CloseParen(Span),
+
+    #[token(",")]
+    Comma,
+
+    /// the keyword of `sep_by(X, Comma)`
+    #[token("sep_by", |l| Span::from(l.span()))]
+    SepBy(Span),
+}
+
+/// what kind of token this is, for error messages
+pub fn describe(kind: TokenKind) -> &'static str {
+    match kind {
+        TokenKind::Equals => "`=`",
+        TokenKind::Colon => "`:`",
+        TokenKind::Pipe => "`|`",
+        TokenKind::Ident => "identifier",
+        TokenKind::Literal => "code literal",
+        TokenKind::Left => "`%left`",
+        TokenKind::Right => "`%right`",
+        TokenKind::NonAssoc => "`%nonassoc`",
+        TokenKind::Prec => "`%prec`",
+        TokenKind::Question => "`?`",
+        TokenKind::Star => "`*`",
+        TokenKind::Plus => "`+`",
+        TokenKind::OpenParen => "`(`",
+        TokenKind::CloseParen => "`)`",
+        TokenKind::Comma => "`,`",
+        TokenKind::SepBy => "`sep_by`",
+    }
+}
+
+/// like [`describe`], but also shows the name of identifiers
+impl Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Ident(name) => write!(f, "identifier `{}`", name.value),
+            token => write!(f, "{}", kind(token).map_or("token", describe)),
+        }
+    }
 }
@@ -6,6 +6,8 @@
 pub mod parser {
     // this is file generated by asterisk-rs 0.1.0
 
+    use crate::grammar::Assoc;
+    use crate::source::{Span, Spanned};
     use crate::spec::ast::*;
     use crate::spec::lex::Token;
 
@@ -18,7 +20,7 @@ pub mod parser {
     pub enum Error {
         Msg(String),
         UnexpectedToken {
-            expected: Vec<Option<Token>>,
+            expected: Vec<Option<TokenKind>>,
             received: Option<Token>,
             state_id: usize,
             remaining_input: Vec<Token>,
@@ -27,7 +29,31 @@ pub mod parser {
 
     impl std::fmt::Display for Error {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            write!(f, "{self:?}")
+            let (expected, received) = match self {
+                Error::Msg(m) => return write!(f, "{m}"),
+                Error::UnexpectedToken {
+                    expected, received, ..
+                } => (expected, received),
+            };
+            match received {
+                Some(token) => match kind(token) {
+                    Some(kind) => write!(f, "unexpected `{kind}`")?,
+                    None => write!(f, "unexpected `{token:?}`")?,
+                },
+                None => write!(f, "unexpected end of input")?,
+            }
+            let expected: Vec<String> = expected
+                .iter()
+                .map(|x| match x {
+                    Some(kind) => format!("`{kind}`"),
+                    None => "end of input".to_owned(),
+                })
+                .collect();
+            match expected.split_last() {
+                None => Ok(()),
+                Some((last, [])) => write!(f, ", expected {last}"),
+                Some((last, rest)) => write!(f, ", expected one of {} or {last}", rest.join(", ")),
+            }
         }
     }
 
@@ -41,6 +67,74 @@ pub mod parser {
 
     pub type Result<T> = std::result::Result<T, Error>;
 
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum TokenKind {
+        Ident,
+        Equals,
+        Literal,
+        Left,
+        Right,
+        NonAssoc,
+        Colon,
+        Pipe,
+        Prec,
+        Question,
+        Star,
+        Plus,
+        OpenParen,
+        CloseParen,
+        SepBy,
+        Comma,
+    }
+
+    impl std::fmt::Display for TokenKind {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let name = match *self {
+                TokenKind::Ident => "Ident",
+                TokenKind::Equals => "Equals",
+                TokenKind::Literal => "Literal",
+                TokenKind::Left => "Left",
+                TokenKind::Right => "Right",
+                TokenKind::NonAssoc => "NonAssoc",
+                TokenKind::Colon => "Colon",
+                TokenKind::Pipe => "Pipe",
+                TokenKind::Prec => "Prec",
+                TokenKind::Question => "Question",
+                TokenKind::Star => "Star",
+                TokenKind::Plus => "Plus",
+                TokenKind::OpenParen => "OpenParen",
+                TokenKind::CloseParen => "CloseParen",
+                TokenKind::SepBy => "SepBy",
+                TokenKind::Comma => "Comma",
+            };
+            write!(f, "{name}")
+        }
+    }
+
+    /// the kind of `token`, `None` for tokens that are not terminals of the grammar
+    #[allow(unreachable_patterns)]
+    pub fn kind(token: &Token) -> Option<TokenKind> {
+        match token {
+            Token::Ident(_) => Some(TokenKind::Ident),
+            Token::Equals => Some(TokenKind::Equals),
+            Token::Literal(_) => Some(TokenKind::Literal),
+            Token::Left(_) => Some(TokenKind::Left),
+            Token::Right(_) => Some(TokenKind::Right),
+            Token::NonAssoc(_) => Some(TokenKind::NonAssoc),
+            Token::Colon => Some(TokenKind::Colon),
+            Token::Pipe => Some(TokenKind::Pipe),
+            Token::Prec => Some(TokenKind::Prec),
+            Token::Question(_) => Some(TokenKind::Question),
+            Token::Star(_) => Some(TokenKind::Star),
+            Token::Plus(_) => Some(TokenKind::Plus),
+            Token::OpenParen(_) => Some(TokenKind::OpenParen),
+            Token::CloseParen(_) => Some(TokenKind::CloseParen),
+            Token::SepBy(_) => Some(TokenKind::SepBy),
+            Token::Comma => Some(TokenKind::Comma),
+            _ => None,
+        }
+    }
+
     #[derive(Debug, Clone, Copy)]
     enum State {
         State1,
@@ -67,38 +161,122 @@ pub mod parser {
         State22,
         State23,
         State24,
+        State25,
+        State26,
+        State27,
+        State28,
+        State29,
+        State30,
+        State31,
+        State32,
+        State33,
+        State34,
+        State35,
+        State36,
+        State37,
+        State38,
+        State39,
+        State40,
+        State41,
+        State42,
+        State43,
+        State44,
+        State45,
+        State46,
+        State47,
+        State48,
+        State49,
+        State50,
+        State51,
+        State52,
+        State53,
+        State54,
+        State55,
+        State56,
+        State57,
+        State58,
+        State59,
+        State60,
+        State61,
+        State62,
+        State63,
+        State64,
+        State65,
+        State66,
+        State67,
+        State68,
+        State69,
+        State70,
+        State71,
+        State72,
+        State73,
+        State74,
+        State75,
+        State76,
+        State77,
+        State78,
+        State79,
+        State80,
+        State81,
+        State82,
+        State83,
+        State84,
+        State85,
+        State86,
+        State87,
+        State88,
+        State89,
+        State90,
+        State91,
     }
 
     #[allow(dead_code)]
     enum StackValue {
+        NonTerm_Configs(Vec<Config>),
         NonTerm_Rules(Vec<Rule>),
         NonTerm_Grammar(Spec),
-        NonTerm_Configs(Vec<(String, String)>),
-        NonTerm_Config((String, String)),
+        NonTerm_Config(Config),
         NonTerm_Rule(Rule),
         NonTerm_CaseList(Vec<Expansion>),
         NonTerm_Case(Expansion),
-        NonTerm_Idents(Vec<String>),
+        NonTerm_Bindings(Vec<Binding>),
+        NonTerm_Binding(Binding),
+        NonTerm_Item(Spanned<Item>),
+        NonTerm_Items(Vec<Spanned<Item>>),
+        NonTerm_Atom(Spanned<Item>),
         NonTerm_S0(Spec),
-        Term_Ident(String),
-        Term_Literal(String),
+        Term_Ident(Spanned<String>),
+        Term_Literal(Spanned<String>),
+        Term_Left(Vec<Spanned<String>>),
+        Term_Right(Vec<Spanned<String>>),
+        Term_NonAssoc(Vec<Spanned<String>>),
+        Term_Question(Span),
+        Term_Star(Span),
+        Term_Plus(Span),
+        Term_OpenParen(Span),
+        Term_CloseParen(Span),
+        Term_SepBy(Span),
         None,
     }
 
     enum NonTerm {
+        Configs,
         Rules,
         Grammar,
-        Configs,
         Config,
         Rule,
         CaseList,
         Case,
-        Idents,
+        Bindings,
+        Binding,
+        Item,
+        Items,
+        Atom,
         S0,
     }
 
     enum TokenType {
-        Term(Token),
+        Term(TokenKind),
         NonTerm(NonTerm),
         TermEof,
     }
@@ -125,48 +303,61 @@ pub mod parser {
         }
     }
 
+    pub fn parse_Grammar<I>(tokens: I) -> Result<Spec>
+    where
+        I: Iterator<Item = Token>,
+    {
+        match _parse(tokens, State::State1, NonTerm::S0)? {
+            StackValue::NonTerm_S0(v) => Ok(v),
+            _ => Err(Error::msg("parsed the wrong rule. this is probably a bug")),
+        }
+    }
+
     pub fn parse<I>(tokens: I) -> Result<Spec>
+    where
+        I: Iterator<Item = Token>,
+    {
+        parse_Grammar(tokens)
+    }
+
+    fn _parse<I>(tokens: I, start: State, start_rule: NonTerm) -> Result<StackValue>
     where
         I: Iterator<Item = Token>,
     {
         let mut tokens = tokens.peekable();
-        let mut state = State::State1;
+        let mut state = start;
         let mut stack = Stack(Vec::new());
-        stack.push(
-            State::State1,
-            TokenType::NonTerm(NonTerm::S0),
-            StackValue::None,
-        );
-        fn goto_Rules(state: State) -> Result<State> {
+        stack.push(start, TokenType::NonTerm(start_rule), StackValue::None);
+        fn goto_Configs(state: State) -> Result<State> {
             match state {
-                State::State1 => Ok(State::State21),
-                State::State2 => Ok(State::State15),
-                State::State3 => Ok(State::State14),
+                State::State1 => Ok(State::State6),
                 _ => Err(Error::msg(
-                    "failed to match in Rules. this is probably a bug",
+                    "failed to match in Configs. this is probably a bug",
                 )),
             }
         }
-        fn goto_Grammar(state: State) -> Result<State> {
+        fn goto_Rules(state: State) -> Result<State> {
             match state {
-                State::State1 => Ok(State::State23),
+                State::State1 => Ok(State::State7),
+                State::State6 => Ok(State::State13),
+                State::State10 => Ok(State::State17),
                 _ => Err(Error::msg(
-                    "failed to match in Grammar. this is probably a bug",
+                    "failed to match in Rules. this is probably a bug",
                 )),
             }
         }
-        fn goto_Configs(state: State) -> Result<State> {
+        fn goto_Grammar(state: State) -> Result<State> {
             match state {
-                State::State1 => Ok(State::State2),
+                State::State1 => Ok(State::State8),
                 _ => Err(Error::msg(
-                    "failed to match in Configs. this is probably a bug",
+                    "failed to match in Grammar. this is probably a bug",
                 )),
             }
         }
         fn goto_Config(state: State) -> Result<State> {
             match state {
-                State::State1 => Ok(State::State22),
-                State::State2 => Ok(State::State16),
+                State::State1 => Ok(State::State9),
+                State::State6 => Ok(State::State14),
                 _ => Err(Error::msg(
                     "failed to match in Config. this is probably a bug",
                 )),
@@ -174,9 +365,9 @@ pub mod parser {
         }
         fn goto_Rule(state: State) -> Result<State> {
             match state {
-                State::State1 => Ok(State::State3),
-                State::State2 => Ok(State::State3),
-                State::State3 => Ok(State::State3),
+                State::State1 => Ok(State::State10),
+                State::State6 => Ok(State::State10),
+                State::State10 => Ok(State::State10),
                 _ => Err(Error::msg(
                     "failed to match in Rule. this is probably a bug",
                 )),
@@ -184,8 +375,8 @@ pub mod parser {
         }
         fn goto_CaseList(state: State) -> Result<State> {
             match state {
-                State::State6 => Ok(State::State7),
-                State::State8 => Ok(State::State9),
+                State::State20 => Ok(State::State22),
+                State::State23 => Ok(State::State25),
                 _ => Err(Error::msg(
                     "failed to match in CaseList. this is probably a bug",
                 )),
@@ -193,48 +384,656 @@ pub mod parser {
         }
         fn goto_Case(state: State) -> Result<State> {
             match state {
-                State::State6 => Ok(State::State8),
-                State::State8 => Ok(State::State8),
+                State::State20 => Ok(State::State23),
+                State::State23 => Ok(State::State23),
                 _ => Err(Error::msg(
                     "failed to match in Case. this is probably a bug",
                 )),
             }
         }
-        fn goto_Idents(state: State) -> Result<State> {
+        fn goto_Bindings(state: State) -> Result<State> {
+            match state {
+                State::State21 => Ok(State::State24),
+                _ => Err(Error::msg(
+                    "failed to match in Bindings. this is probably a bug",
+                )),
+            }
+        }
+        fn goto_Binding(state: State) -> Result<State> {
+            match state {
+                State::State24 => Ok(State::State31),
+                _ => Err(Error::msg(
+                    "failed to match in Binding. this is probably a bug",
+                )),
+            }
+        }
+        fn goto_Item(state: State) -> Result<State> {
+            match state {
+                State::State24 => Ok(State::State32),
+                State::State34 => Ok(State::State42),
+                State::State36 => Ok(State::State48),
+                State::State37 => Ok(State::State53),
+                State::State55 => Ok(State::State48),
+                State::State56 => Ok(State::State67),
+                State::State60 => Ok(State::State48),
+                State::State61 => Ok(State::State69),
+                State::State65 => Ok(State::State73),
+                State::State75 => Ok(State::State83),
+                State::State76 => Ok(State::State84),
+                State::State77 => Ok(State::State48),
+                State::State78 => Ok(State::State86),
+                State::State89 => Ok(State::State90),
+                _ => Err(Error::msg(
+                    "failed to match in Item. this is probably a bug",
+                )),
+            }
+        }
+        fn goto_Items(state: State) -> Result<State> {
+            match state {
+                State::State29 => Ok(State::State36),
+                State::State45 => Ok(State::State55),
+                State::State51 => Ok(State::State60),
+                State::State71 => Ok(State::State77),
+                _ => Err(Error::msg(
+                    "failed to match in Items. this is probably a bug",
+                )),
+            }
+        }
+        fn goto_Atom(state: State) -> Result<State> {
             match state {
-                State::State10 => Ok(State::State11),
+                State::State24 => Ok(State::State33),
+                State::State34 => Ok(State::State33),
+                State::State36 => Ok(State::State49),
+                State::State37 => Ok(State::State54),
+                State::State55 => Ok(State::State49),
+                State::State56 => Ok(State::State54),
+                State::State60 => Ok(State::State49),
+                State::State61 => Ok(State::State54),
+                State::State65 => Ok(State::State74),
+                State::State75 => Ok(State::State74),
+                State::State76 => Ok(State::State74),
+                State::State77 => Ok(State::State49),
+                State::State78 => Ok(State::State54),
+                State::State89 => Ok(State::State74),
                 _ => Err(Error::msg(
-                    "failed to match in Idents. this is probably a bug",
+                    "failed to match in Atom. this is probably a bug",
                 )),
             }
         }
+        fn reduce_0(stack: &mut Stack) -> Result<StackValue> {
+            let v1 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Rules), StackValue::NonTerm_Rules(v)) => v,
+                    _ => return Err(Error::msg("expected token Rules to be on the stack")),
+                }
+            };
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Configs), StackValue::NonTerm_Configs(v)) => v,
+                    _ => return Err(Error::msg("expected token Configs to be on the stack")),
+                }
+            };
+            let value = { Spec::new(v0, v1.into_iter().rev().collect()) };
+            Ok(StackValue::NonTerm_Grammar(value))
+        }
+
+        fn reduce_1(stack: &mut Stack) -> Result<StackValue> {
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Rules), StackValue::NonTerm_Rules(v)) => v,
+                    _ => return Err(Error::msg("expected token Rules to be on the stack")),
+                }
+            };
+            let value = { Spec::new(Vec::new(), v0.into_iter().rev().collect()) };
+            Ok(StackValue::NonTerm_Grammar(value))
+        }
+
+        fn reduce_2(stack: &mut Stack) -> Result<StackValue> {
+            let v1 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Config), StackValue::NonTerm_Config(v)) => v,
+                    _ => return Err(Error::msg("expected token Config to be on the stack")),
+                }
+            };
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Configs), StackValue::NonTerm_Configs(v)) => v,
+                    _ => return Err(Error::msg("expected token Configs to be on the stack")),
+                }
+            };
+            let value = { push(v0, v1) };
+            Ok(StackValue::NonTerm_Configs(value))
+        }
+
+        fn reduce_3(stack: &mut Stack) -> Result<StackValue> {
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Config), StackValue::NonTerm_Config(v)) => v,
+                    _ => return Err(Error::msg("expected token Config to be on the stack")),
+                }
+            };
+            let value = { vec![v0] };
+            Ok(StackValue::NonTerm_Configs(value))
+        }
+
+        fn reduce_4(stack: &mut Stack) -> Result<StackValue> {
+            let v2 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Ident), StackValue::Term_Ident(v)) => v,
+                    _ => return Err(Error::msg("expected token `Ident` to be on the stack")),
+                }
+            };
+            let v1 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Equals), StackValue::None) => (),
+                    _ => return Err(Error::msg("expected token `Equals` to be on the stack")),
+                }
+            };
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Ident), StackValue::Term_Ident(v)) => v,
+                    _ => return Err(Error::msg("expected token `Ident` to be on the stack")),
+                }
+            };
+            let value = { Config::Pair(v0, v2) };
+            Ok(StackValue::NonTerm_Config(value))
+        }
+
+        fn reduce_5(stack: &mut Stack) -> Result<StackValue> {
+            let v2 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Literal), StackValue::Term_Literal(v)) => v,
+                    _ => return Err(Error::msg("expected token `Literal` to be on the stack")),
+                }
+            };
+            let v1 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Equals), StackValue::None) => (),
+                    _ => return Err(Error::msg("expected token `Equals` to be on the stack")),
+                }
+            };
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Ident), StackValue::Term_Ident(v)) => v,
+                    _ => return Err(Error::msg("expected token `Ident` to be on the stack")),
+                }
+            };
+            let value = { Config::Pair(v0, v2) };
+            Ok(StackValue::NonTerm_Config(value))
+        }
+
+        fn reduce_6(stack: &mut Stack) -> Result<StackValue> {
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Left), StackValue::Term_Left(v)) => v,
+                    _ => return Err(Error::msg("expected token `Left` to be on the stack")),
+                }
+            };
+            let value = { Config::Precedence(Assoc::Left, v0) };
+            Ok(StackValue::NonTerm_Config(value))
+        }
+
+        fn reduce_7(stack: &mut Stack) -> Result<StackValue> {
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Right), StackValue::Term_Right(v)) => v,
+                    _ => return Err(Error::msg("expected token `Right` to be on the stack")),
+                }
+            };
+            let value = { Config::Precedence(Assoc::Right, v0) };
+            Ok(StackValue::NonTerm_Config(value))
+        }
+
+        fn reduce_8(stack: &mut Stack) -> Result<StackValue> {
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::NonAssoc), StackValue::Term_NonAssoc(v)) => v,
+                    _ => return Err(Error::msg("expected token `NonAssoc` to be on the stack")),
+                }
+            };
+            let value = { Config::Precedence(Assoc::Nonassoc, v0) };
+            Ok(StackValue::NonTerm_Config(value))
+        }
+
+        fn reduce_9(stack: &mut Stack) -> Result<StackValue> {
+            let v1 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Rules), StackValue::NonTerm_Rules(v)) => v,
+                    _ => return Err(Error::msg("expected token Rules to be on the stack")),
+                }
+            };
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Rule), StackValue::NonTerm_Rule(v)) => v,
+                    _ => return Err(Error::msg("expected token Rule to be on the stack")),
+                }
+            };
+            let value = { push(v1, v0) };
+            Ok(StackValue::NonTerm_Rules(value))
+        }
+
+        fn reduce_10(stack: &mut Stack) -> Result<StackValue> {
+            let value = { vec![] };
+            Ok(StackValue::NonTerm_Rules(value))
+        }
+
+        fn reduce_11(stack: &mut Stack) -> Result<StackValue> {
+            let v3 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::CaseList), StackValue::NonTerm_CaseList(v)) => {
+                        v
+                    }
+                    _ => return Err(Error::msg("expected token CaseList to be on the stack")),
+                }
+            };
+            let v2 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Literal), StackValue::Term_Literal(v)) => v,
+                    _ => return Err(Error::msg("expected token `Literal` to be on the stack")),
+                }
+            };
+            let v1 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Colon), StackValue::None) => (),
+                    _ => return Err(Error::msg("expected token `Colon` to be on the stack")),
+                }
+            };
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Ident), StackValue::Term_Ident(v)) => v,
+                    _ => return Err(Error::msg("expected token `Ident` to be on the stack")),
+                }
+            };
+            let value = { Rule::new(v0, v2, v3) };
+            Ok(StackValue::NonTerm_Rule(value))
+        }
+
+        fn reduce_12(stack: &mut Stack) -> Result<StackValue> {
+            let v1 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::CaseList), StackValue::NonTerm_CaseList(v)) => {
+                        v
+                    }
+                    _ => return Err(Error::msg("expected token CaseList to be on the stack")),
+                }
+            };
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Case), StackValue::NonTerm_Case(v)) => v,
+                    _ => return Err(Error::msg("expected token Case to be on the stack")),
+                }
+            };
+            let value = { push(v1, v0) };
+            Ok(StackValue::NonTerm_CaseList(value))
+        }
+
+        fn reduce_13(stack: &mut Stack) -> Result<StackValue> {
+            let value = { vec![] };
+            Ok(StackValue::NonTerm_CaseList(value))
+        }
+
+        fn reduce_14(stack: &mut Stack) -> Result<StackValue> {
+            let v2 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Literal), StackValue::Term_Literal(v)) => v,
+                    _ => return Err(Error::msg("expected token `Literal` to be on the stack")),
+                }
+            };
+            let v1 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Bindings), StackValue::NonTerm_Bindings(v)) => {
+                        v
+                    }
+                    _ => return Err(Error::msg("expected token Bindings to be on the stack")),
+                }
+            };
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Pipe), StackValue::None) => (),
+                    _ => return Err(Error::msg("expected token `Pipe` to be on the stack")),
+                }
+            };
+            let value = { Expansion::new(v1, v2, None) };
+            Ok(StackValue::NonTerm_Case(value))
+        }
+
+        fn reduce_15(stack: &mut Stack) -> Result<StackValue> {
+            let v4 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Literal), StackValue::Term_Literal(v)) => v,
+                    _ => return Err(Error::msg("expected token `Literal` to be on the stack")),
+                }
+            };
+            let v3 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Ident), StackValue::Term_Ident(v)) => v,
+                    _ => return Err(Error::msg("expected token `Ident` to be on the stack")),
+                }
+            };
+            let v2 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Prec), StackValue::None) => (),
+                    _ => return Err(Error::msg("expected token `Prec` to be on the stack")),
+                }
+            };
+            let v1 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Bindings), StackValue::NonTerm_Bindings(v)) => {
+                        v
+                    }
+                    _ => return Err(Error::msg("expected token Bindings to be on the stack")),
+                }
+            };
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Pipe), StackValue::None) => (),
+                    _ => return Err(Error::msg("expected token `Pipe` to be on the stack")),
+                }
+            };
+            let value = { Expansion::new(v1, v4, Some(v3)) };
+            Ok(StackValue::NonTerm_Case(value))
+        }
+
+        fn reduce_16(stack: &mut Stack) -> Result<StackValue> {
+            let v1 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Binding), StackValue::NonTerm_Binding(v)) => v,
+                    _ => return Err(Error::msg("expected token Binding to be on the stack")),
+                }
+            };
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Bindings), StackValue::NonTerm_Bindings(v)) => {
+                        v
+                    }
+                    _ => return Err(Error::msg("expected token Bindings to be on the stack")),
+                }
+            };
+            let value = { push(v0, v1) };
+            Ok(StackValue::NonTerm_Bindings(value))
+        }
+
+        fn reduce_17(stack: &mut Stack) -> Result<StackValue> {
+            let value = { vec![] };
+            Ok(StackValue::NonTerm_Bindings(value))
+        }
+
+        fn reduce_18(stack: &mut Stack) -> Result<StackValue> {
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Item), StackValue::NonTerm_Item(v)) => v,
+                    _ => return Err(Error::msg("expected token Item to be on the stack")),
+                }
+            };
+            let value = { Binding::new(None, v0) };
+            Ok(StackValue::NonTerm_Binding(value))
+        }
+
+        fn reduce_19(stack: &mut Stack) -> Result<StackValue> {
+            let v2 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Item), StackValue::NonTerm_Item(v)) => v,
+                    _ => return Err(Error::msg("expected token Item to be on the stack")),
+                }
+            };
+            let v1 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Colon), StackValue::None) => (),
+                    _ => return Err(Error::msg("expected token `Colon` to be on the stack")),
+                }
+            };
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Ident), StackValue::Term_Ident(v)) => v,
+                    _ => return Err(Error::msg("expected token `Ident` to be on the stack")),
+                }
+            };
+            let value = { Binding::new(Some(v0), v2) };
+            Ok(StackValue::NonTerm_Binding(value))
+        }
+
+        fn reduce_20(stack: &mut Stack) -> Result<StackValue> {
+            let v1 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Item), StackValue::NonTerm_Item(v)) => v,
+                    _ => return Err(Error::msg("expected token Item to be on the stack")),
+                }
+            };
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Items), StackValue::NonTerm_Items(v)) => v,
+                    _ => return Err(Error::msg("expected token Items to be on the stack")),
+                }
+            };
+            let value = { push(v0, v1) };
+            Ok(StackValue::NonTerm_Items(value))
+        }
+
+        fn reduce_21(stack: &mut Stack) -> Result<StackValue> {
+            let value = { vec![] };
+            Ok(StackValue::NonTerm_Items(value))
+        }
+
+        fn reduce_22(stack: &mut Stack) -> Result<StackValue> {
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Atom), StackValue::NonTerm_Atom(v)) => v,
+                    _ => return Err(Error::msg("expected token Atom to be on the stack")),
+                }
+            };
+            let value = { v0 };
+            Ok(StackValue::NonTerm_Item(value))
+        }
+
+        fn reduce_23(stack: &mut Stack) -> Result<StackValue> {
+            let v1 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Question), StackValue::Term_Question(v)) => v,
+                    _ => return Err(Error::msg("expected token `Question` to be on the stack")),
+                }
+            };
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Item), StackValue::NonTerm_Item(v)) => v,
+                    _ => return Err(Error::msg("expected token Item to be on the stack")),
+                }
+            };
+            let value = { Item::repeat(v0, Item::Optional, v1) };
+            Ok(StackValue::NonTerm_Item(value))
+        }
+
+        fn reduce_24(stack: &mut Stack) -> Result<StackValue> {
+            let v1 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Star), StackValue::Term_Star(v)) => v,
+                    _ => return Err(Error::msg("expected token `Star` to be on the stack")),
+                }
+            };
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Item), StackValue::NonTerm_Item(v)) => v,
+                    _ => return Err(Error::msg("expected token Item to be on the stack")),
+                }
+            };
+            let value = { Item::repeat(v0, Item::Star, v1) };
+            Ok(StackValue::NonTerm_Item(value))
+        }
+
+        fn reduce_25(stack: &mut Stack) -> Result<StackValue> {
+            let v1 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Plus), StackValue::Term_Plus(v)) => v,
+                    _ => return Err(Error::msg("expected token `Plus` to be on the stack")),
+                }
+            };
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Item), StackValue::NonTerm_Item(v)) => v,
+                    _ => return Err(Error::msg("expected token Item to be on the stack")),
+                }
+            };
+            let value = { Item::repeat(v0, Item::Plus, v1) };
+            Ok(StackValue::NonTerm_Item(value))
+        }
+
+        fn reduce_26(stack: &mut Stack) -> Result<StackValue> {
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Ident), StackValue::Term_Ident(v)) => v,
+                    _ => return Err(Error::msg("expected token `Ident` to be on the stack")),
+                }
+            };
+            let value = { Item::symbol(v0) };
+            Ok(StackValue::NonTerm_Atom(value))
+        }
+
+        fn reduce_27(stack: &mut Stack) -> Result<StackValue> {
+            let v2 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::CloseParen), StackValue::Term_CloseParen(v)) => {
+                        v
+                    }
+                    _ => return Err(Error::msg("expected token `CloseParen` to be on the stack")),
+                }
+            };
+            let v1 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Items), StackValue::NonTerm_Items(v)) => v,
+                    _ => return Err(Error::msg("expected token Items to be on the stack")),
+                }
+            };
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::OpenParen), StackValue::Term_OpenParen(v)) => v,
+                    _ => return Err(Error::msg("expected token `OpenParen` to be on the stack")),
+                }
+            };
+            let value = { Item::group(v0, v1, v2) };
+            Ok(StackValue::NonTerm_Atom(value))
+        }
+
+        fn reduce_28(stack: &mut Stack) -> Result<StackValue> {
+            let v5 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::CloseParen), StackValue::Term_CloseParen(v)) => {
+                        v
+                    }
+                    _ => return Err(Error::msg("expected token `CloseParen` to be on the stack")),
+                }
+            };
+            let v4 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Item), StackValue::NonTerm_Item(v)) => v,
+                    _ => return Err(Error::msg("expected token Item to be on the stack")),
+                }
+            };
+            let v3 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::Comma), StackValue::None) => (),
+                    _ => return Err(Error::msg("expected token `Comma` to be on the stack")),
+                }
+            };
+            let v2 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Item), StackValue::NonTerm_Item(v)) => v,
+                    _ => return Err(Error::msg("expected token Item to be on the stack")),
+                }
+            };
+            let v1 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::OpenParen), StackValue::Term_OpenParen(v)) => v,
+                    _ => return Err(Error::msg("expected token `OpenParen` to be on the stack")),
+                }
+            };
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::Term(TokenKind::SepBy), StackValue::Term_SepBy(v)) => v,
+                    _ => return Err(Error::msg("expected token `SepBy` to be on the stack")),
+                }
+            };
+            let value = { Item::sep_by(v0, v2, v4, v5) };
+            Ok(StackValue::NonTerm_Atom(value))
+        }
+
+        fn reduce_29(stack: &mut Stack) -> Result<StackValue> {
+            let v1 = {
+                match stack.pop()? {
+                    (_, TokenType::TermEof, StackValue::None) => (),
+                    _ => return Err(Error::msg("expected token ＄ to be on the stack")),
+                }
+            };
+            let v0 = {
+                match stack.pop()? {
+                    (_, TokenType::NonTerm(NonTerm::Grammar), StackValue::NonTerm_Grammar(v)) => v,
+                    _ => return Err(Error::msg("expected token Grammar to be on the stack")),
+                }
+            };
+            Ok(StackValue::NonTerm_S0(v0))
+        }
 
         loop {
             match state {
                 State::State1 => match tokens.peek() {
                     Some(Token::Ident(_)) => {
-                        let head = tokens.next().unwrap();
-                        let Token::Ident(value) = head else {
+                        let Some(Token::Ident(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State2;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Ident),
+                            StackValue::Term_Ident(value),
+                        );
+                    }
+                    Some(Token::Left(_)) => {
+                        let Some(Token::Left(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State3;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Left),
+                            StackValue::Term_Left(value),
+                        );
+                    }
+                    Some(Token::Right(_)) => {
+                        let Some(Token::Right(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State4;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Right),
+                            StackValue::Term_Right(value),
+                        );
+                    }
+                    Some(Token::NonAssoc(_)) => {
+                        let Some(Token::NonAssoc(value)) = tokens.next() else {
                             unreachable!()
                         };
-                        let head = Token::Ident(Default::default());
-                        state = State::State17;
-                        stack.push(state, TokenType::Term(head), StackValue::Term_Ident(value));
+                        state = State::State5;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::NonAssoc),
+                            StackValue::Term_NonAssoc(value),
+                        );
                     }
                     None => {
-                        let value = { vec![] };
+                        let value = reduce_10(&mut stack)?;
                         let &(before, _, _) = stack.peek()?;
                         let goto = goto_Rules(before)?;
                         state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Rules),
-                            StackValue::NonTerm_Rules(value),
-                        );
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Rules), value);
                     }
                     _ => {
                         return Err(Error::UnexpectedToken {
-                            expected: vec![None, Some(Token::Ident(Default::default()))],
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Left),
+                                Some(TokenKind::Right),
+                                Some(TokenKind::NonAssoc),
+                                None,
+                            ],
                             received: tokens.next(),
                             state_id: 1,
                             remaining_input: tokens.collect(),
@@ -243,29 +1042,19 @@ pub mod parser {
                 },
 
                 State::State2 => match tokens.peek() {
-                    Some(Token::Ident(_)) => {
-                        let head = tokens.next().unwrap();
-                        let Token::Ident(value) = head else {
-                            unreachable!()
-                        };
-                        let head = Token::Ident(Default::default());
-                        state = State::State17;
-                        stack.push(state, TokenType::Term(head), StackValue::Term_Ident(value));
+                    Some(Token::Equals) => {
+                        tokens.next();
+                        state = State::State11;
+                        stack.push(state, TokenType::Term(TokenKind::Equals), StackValue::None);
                     }
-                    None => {
-                        let value = { vec![] };
-                        let &(before, _, _) = stack.peek()?;
-                        let goto = goto_Rules(before)?;
-                        state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Rules),
-                            StackValue::NonTerm_Rules(value),
-                        );
+                    Some(Token::Colon) => {
+                        tokens.next();
+                        state = State::State12;
+                        stack.push(state, TokenType::Term(TokenKind::Colon), StackValue::None);
                     }
                     _ => {
                         return Err(Error::UnexpectedToken {
-                            expected: vec![None, Some(Token::Ident(Default::default()))],
+                            expected: vec![Some(TokenKind::Equals), Some(TokenKind::Colon)],
                             received: tokens.next(),
                             state_id: 2,
                             remaining_input: tokens.collect(),
@@ -275,28 +1064,49 @@ pub mod parser {
 
                 State::State3 => match tokens.peek() {
                     Some(Token::Ident(_)) => {
-                        let head = tokens.next().unwrap();
-                        let Token::Ident(value) = head else {
-                            unreachable!()
-                        };
-                        let head = Token::Ident(Default::default());
-                        state = State::State4;
-                        stack.push(state, TokenType::Term(head), StackValue::Term_Ident(value));
+                        let value = reduce_6(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Config(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
+                    }
+                    Some(Token::Left(_)) => {
+                        let value = reduce_6(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Config(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
+                    }
+                    Some(Token::Right(_)) => {
+                        let value = reduce_6(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Config(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
+                    }
+                    Some(Token::NonAssoc(_)) => {
+                        let value = reduce_6(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Config(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
                     }
                     None => {
-                        let value = { vec![] };
+                        let value = reduce_6(&mut stack)?;
                         let &(before, _, _) = stack.peek()?;
-                        let goto = goto_Rules(before)?;
+                        let goto = goto_Config(before)?;
                         state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Rules),
-                            StackValue::NonTerm_Rules(value),
-                        );
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
                     }
                     _ => {
                         return Err(Error::UnexpectedToken {
-                            expected: vec![Some(Token::Ident(Default::default())), None],
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Left),
+                                Some(TokenKind::Right),
+                                Some(TokenKind::NonAssoc),
+                                None,
+                            ],
                             received: tokens.next(),
                             state_id: 3,
                             remaining_input: tokens.collect(),
@@ -305,14 +1115,50 @@ pub mod parser {
                 },
 
                 State::State4 => match tokens.peek() {
-                    Some(Token::Colon) => {
-                        let head = tokens.next().unwrap();
-                        state = State::State5;
-                        stack.push(state, TokenType::Term(head), StackValue::None);
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_7(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Config(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
+                    }
+                    Some(Token::Left(_)) => {
+                        let value = reduce_7(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Config(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
+                    }
+                    Some(Token::Right(_)) => {
+                        let value = reduce_7(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Config(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
+                    }
+                    Some(Token::NonAssoc(_)) => {
+                        let value = reduce_7(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Config(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
+                    }
+                    None => {
+                        let value = reduce_7(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Config(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
                     }
                     _ => {
                         return Err(Error::UnexpectedToken {
-                            expected: vec![Some(Token::Colon)],
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Left),
+                                Some(TokenKind::Right),
+                                Some(TokenKind::NonAssoc),
+                                None,
+                            ],
                             received: tokens.next(),
                             state_id: 4,
                             remaining_input: tokens.collect(),
@@ -321,255 +1167,151 @@ pub mod parser {
                 },
 
                 State::State5 => match tokens.peek() {
-                    Some(Token::Literal(_)) => {
-                        let head = tokens.next().unwrap();
-                        let Token::Literal(value) = head else {
-                            unreachable!()
-                        };
-                        let head = Token::Literal(Default::default());
-                        state = State::State6;
-                        stack.push(
-                            state,
-                            TokenType::Term(head),
-                            StackValue::Term_Literal(value),
-                        );
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_8(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Config(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
                     }
-                    _ => {
-                        return Err(Error::UnexpectedToken {
-                            expected: vec![Some(Token::Literal(Default::default()))],
-                            received: tokens.next(),
-                            state_id: 5,
-                            remaining_input: tokens.collect(),
-                        })
+                    Some(Token::Left(_)) => {
+                        let value = reduce_8(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Config(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
                     }
-                },
-
-                State::State6 => match tokens.peek() {
-                    Some(Token::Ident(_)) => {
-                        let value = { vec![] };
+                    Some(Token::Right(_)) => {
+                        let value = reduce_8(&mut stack)?;
                         let &(before, _, _) = stack.peek()?;
-                        let goto = goto_CaseList(before)?;
+                        let goto = goto_Config(before)?;
                         state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::CaseList),
-                            StackValue::NonTerm_CaseList(value),
-                        );
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
                     }
-                    Some(Token::Pipe) => {
-                        let head = tokens.next().unwrap();
-                        state = State::State10;
-                        stack.push(state, TokenType::Term(head), StackValue::None);
+                    Some(Token::NonAssoc(_)) => {
+                        let value = reduce_8(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Config(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
                     }
                     None => {
-                        let value = { vec![] };
+                        let value = reduce_8(&mut stack)?;
                         let &(before, _, _) = stack.peek()?;
-                        let goto = goto_CaseList(before)?;
+                        let goto = goto_Config(before)?;
                         state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::CaseList),
-                            StackValue::NonTerm_CaseList(value),
-                        );
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
                     }
                     _ => {
                         return Err(Error::UnexpectedToken {
                             expected: vec![
-                                Some(Token::Ident(Default::default())),
-                                Some(Token::Pipe),
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Left),
+                                Some(TokenKind::Right),
+                                Some(TokenKind::NonAssoc),
                                 None,
                             ],
                             received: tokens.next(),
-                            state_id: 6,
+                            state_id: 5,
                             remaining_input: tokens.collect(),
                         })
                     }
                 },
 
-                State::State7 => match tokens.peek() {
+                State::State6 => match tokens.peek() {
                     Some(Token::Ident(_)) => {
-                        let v3 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::CaseList),
-                                    StackValue::NonTerm_CaseList(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token CaseList to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v2 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::Term(Token::Literal(_)),
-                                    StackValue::Term_Literal(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Literal` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v1 = {
-                            match stack.pop()? {
-                                (_, TokenType::Term(Token::Colon), StackValue::None) => (),
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Colon` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v0 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::Term(Token::Ident(_)),
-                                    StackValue::Term_Ident(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Ident` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let value = {
-                            Rule {
-                                name: v0,
-                                typ: v2,
-                                expansions: v3,
-                            }
+                        let Some(Token::Ident(value)) = tokens.next() else {
+                            unreachable!()
                         };
-                        let &(before, _, _) = stack.peek()?;
-                        let goto = goto_Rule(before)?;
-                        state = goto;
+                        state = State::State2;
                         stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Rule),
-                            StackValue::NonTerm_Rule(value),
+                            state,
+                            TokenType::Term(TokenKind::Ident),
+                            StackValue::Term_Ident(value),
                         );
                     }
-                    None => {
-                        let v3 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::CaseList),
-                                    StackValue::NonTerm_CaseList(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token CaseList to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v2 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::Term(Token::Literal(_)),
-                                    StackValue::Term_Literal(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Literal` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v1 = {
-                            match stack.pop()? {
-                                (_, TokenType::Term(Token::Colon), StackValue::None) => (),
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Colon` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v0 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::Term(Token::Ident(_)),
-                                    StackValue::Term_Ident(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Ident` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let value = {
-                            Rule {
-                                name: v0,
-                                typ: v2,
-                                expansions: v3,
-                            }
+                    Some(Token::Left(_)) => {
+                        let Some(Token::Left(value)) = tokens.next() else {
+                            unreachable!()
                         };
-                        let &(before, _, _) = stack.peek()?;
-                        let goto = goto_Rule(before)?;
-                        state = goto;
+                        state = State::State3;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Left),
+                            StackValue::Term_Left(value),
+                        );
+                    }
+                    Some(Token::Right(_)) => {
+                        let Some(Token::Right(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State4;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Right),
+                            StackValue::Term_Right(value),
+                        );
+                    }
+                    Some(Token::NonAssoc(_)) => {
+                        let Some(Token::NonAssoc(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State5;
                         stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Rule),
-                            StackValue::NonTerm_Rule(value),
+                            state,
+                            TokenType::Term(TokenKind::NonAssoc),
+                            StackValue::Term_NonAssoc(value),
                         );
                     }
+                    None => {
+                        let value = reduce_10(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Rules(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Rules), value);
+                    }
                     _ => {
                         return Err(Error::UnexpectedToken {
-                            expected: vec![None, Some(Token::Ident(Default::default()))],
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Left),
+                                Some(TokenKind::Right),
+                                Some(TokenKind::NonAssoc),
+                                None,
+                            ],
                             received: tokens.next(),
-                            state_id: 7,
+                            state_id: 6,
                             remaining_input: tokens.collect(),
                         })
                     }
                 },
 
-                State::State8 => match tokens.peek() {
-                    Some(Token::Ident(_)) => {
-                        let value = { vec![] };
+                State::State7 => match tokens.peek() {
+                    None => {
+                        let value = reduce_1(&mut stack)?;
                         let &(before, _, _) = stack.peek()?;
-                        let goto = goto_CaseList(before)?;
+                        let goto = goto_Grammar(before)?;
                         state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::CaseList),
-                            StackValue::NonTerm_CaseList(value),
-                        );
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Grammar), value);
                     }
-                    Some(Token::Pipe) => {
-                        let head = tokens.next().unwrap();
-                        state = State::State10;
-                        stack.push(state, TokenType::Term(head), StackValue::None);
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![None],
+                            received: tokens.next(),
+                            state_id: 7,
+                            remaining_input: tokens.collect(),
+                        })
                     }
+                },
+
+                State::State8 => match tokens.peek() {
                     None => {
-                        let value = { vec![] };
-                        let &(before, _, _) = stack.peek()?;
-                        let goto = goto_CaseList(before)?;
-                        state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::CaseList),
-                            StackValue::NonTerm_CaseList(value),
-                        );
+                        state = State::State15;
+                        stack.push(state, TokenType::TermEof, StackValue::None);
                     }
                     _ => {
                         return Err(Error::UnexpectedToken {
-                            expected: vec![
-                                None,
-                                Some(Token::Pipe),
-                                Some(Token::Ident(Default::default())),
-                            ],
+                            expected: vec![None],
                             received: tokens.next(),
                             state_id: 8,
                             remaining_input: tokens.collect(),
@@ -579,86 +1321,49 @@ pub mod parser {
 
                 State::State9 => match tokens.peek() {
                     Some(Token::Ident(_)) => {
-                        let v1 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::CaseList),
-                                    StackValue::NonTerm_CaseList(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token CaseList to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v0 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::Case),
-                                    StackValue::NonTerm_Case(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token Case to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let value = { push(v1, v0) };
+                        let value = reduce_3(&mut stack)?;
                         let &(before, _, _) = stack.peek()?;
-                        let goto = goto_CaseList(before)?;
+                        let goto = goto_Configs(before)?;
                         state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::CaseList),
-                            StackValue::NonTerm_CaseList(value),
-                        );
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Configs), value);
+                    }
+                    Some(Token::Left(_)) => {
+                        let value = reduce_3(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Configs(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Configs), value);
+                    }
+                    Some(Token::Right(_)) => {
+                        let value = reduce_3(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Configs(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Configs), value);
+                    }
+                    Some(Token::NonAssoc(_)) => {
+                        let value = reduce_3(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Configs(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Configs), value);
                     }
                     None => {
-                        let v1 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::CaseList),
-                                    StackValue::NonTerm_CaseList(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token CaseList to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v0 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::Case),
-                                    StackValue::NonTerm_Case(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token Case to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let value = { push(v1, v0) };
+                        let value = reduce_3(&mut stack)?;
                         let &(before, _, _) = stack.peek()?;
-                        let goto = goto_CaseList(before)?;
+                        let goto = goto_Configs(before)?;
                         state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::CaseList),
-                            StackValue::NonTerm_CaseList(value),
-                        );
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Configs), value);
                     }
                     _ => {
                         return Err(Error::UnexpectedToken {
-                            expected: vec![None, Some(Token::Ident(Default::default()))],
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Left),
+                                Some(TokenKind::Right),
+                                Some(TokenKind::NonAssoc),
+                                None,
+                            ],
                             received: tokens.next(),
                             state_id: 9,
                             remaining_input: tokens.collect(),
@@ -668,33 +1373,26 @@ pub mod parser {
 
                 State::State10 => match tokens.peek() {
                     Some(Token::Ident(_)) => {
-                        let value = { vec![] };
-                        let &(before, _, _) = stack.peek()?;
-                        let goto = goto_Idents(before)?;
-                        state = goto;
+                        let Some(Token::Ident(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State16;
                         stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Idents),
-                            StackValue::NonTerm_Idents(value),
+                            state,
+                            TokenType::Term(TokenKind::Ident),
+                            StackValue::Term_Ident(value),
                         );
                     }
-                    Some(Token::Literal(_)) => {
-                        let value = { vec![] };
+                    None => {
+                        let value = reduce_10(&mut stack)?;
                         let &(before, _, _) = stack.peek()?;
-                        let goto = goto_Idents(before)?;
+                        let goto = goto_Rules(before)?;
                         state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Idents),
-                            StackValue::NonTerm_Idents(value),
-                        );
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Rules), value);
                     }
                     _ => {
                         return Err(Error::UnexpectedToken {
-                            expected: vec![
-                                Some(Token::Literal(Default::default())),
-                                Some(Token::Ident(Default::default())),
-                            ],
+                            expected: vec![Some(TokenKind::Ident), None],
                             received: tokens.next(),
                             state_id: 10,
                             remaining_input: tokens.collect(),
@@ -704,33 +1402,30 @@ pub mod parser {
 
                 State::State11 => match tokens.peek() {
                     Some(Token::Ident(_)) => {
-                        let head = tokens.next().unwrap();
-                        let Token::Ident(value) = head else {
+                        let Some(Token::Ident(value)) = tokens.next() else {
                             unreachable!()
                         };
-                        let head = Token::Ident(Default::default());
-                        state = State::State13;
-                        stack.push(state, TokenType::Term(head), StackValue::Term_Ident(value));
+                        state = State::State18;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Ident),
+                            StackValue::Term_Ident(value),
+                        );
                     }
                     Some(Token::Literal(_)) => {
-                        let head = tokens.next().unwrap();
-                        let Token::Literal(value) = head else {
+                        let Some(Token::Literal(value)) = tokens.next() else {
                             unreachable!()
                         };
-                        let head = Token::Literal(Default::default());
-                        state = State::State12;
+                        state = State::State19;
                         stack.push(
                             state,
-                            TokenType::Term(head),
+                            TokenType::Term(TokenKind::Literal),
                             StackValue::Term_Literal(value),
                         );
                     }
                     _ => {
                         return Err(Error::UnexpectedToken {
-                            expected: vec![
-                                Some(Token::Literal(Default::default())),
-                                Some(Token::Ident(Default::default())),
-                            ],
+                            expected: vec![Some(TokenKind::Ident), Some(TokenKind::Literal)],
                             received: tokens.next(),
                             state_id: 11,
                             remaining_input: tokens.collect(),
@@ -739,175 +1434,20 @@ pub mod parser {
                 },
 
                 State::State12 => match tokens.peek() {
-                    Some(Token::Ident(_)) => {
-                        let v2 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::Term(Token::Literal(_)),
-                                    StackValue::Term_Literal(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Literal` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v1 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::Idents),
-                                    StackValue::NonTerm_Idents(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token Idents to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v0 = {
-                            match stack.pop()? {
-                                (_, TokenType::Term(Token::Pipe), StackValue::None) => (),
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Pipe` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let value = {
-                            Expansion {
-                                tokens: v1,
-                                code: v2,
-                            }
-                        };
-                        let &(before, _, _) = stack.peek()?;
-                        let goto = goto_Case(before)?;
-                        state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Case),
-                            StackValue::NonTerm_Case(value),
-                        );
-                    }
-                    Some(Token::Pipe) => {
-                        let v2 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::Term(Token::Literal(_)),
-                                    StackValue::Term_Literal(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Literal` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v1 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::Idents),
-                                    StackValue::NonTerm_Idents(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token Idents to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v0 = {
-                            match stack.pop()? {
-                                (_, TokenType::Term(Token::Pipe), StackValue::None) => (),
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Pipe` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let value = {
-                            Expansion {
-                                tokens: v1,
-                                code: v2,
-                            }
-                        };
-                        let &(before, _, _) = stack.peek()?;
-                        let goto = goto_Case(before)?;
-                        state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Case),
-                            StackValue::NonTerm_Case(value),
-                        );
-                    }
-                    None => {
-                        let v2 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::Term(Token::Literal(_)),
-                                    StackValue::Term_Literal(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Literal` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v1 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::Idents),
-                                    StackValue::NonTerm_Idents(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token Idents to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v0 = {
-                            match stack.pop()? {
-                                (_, TokenType::Term(Token::Pipe), StackValue::None) => (),
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Pipe` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let value = {
-                            Expansion {
-                                tokens: v1,
-                                code: v2,
-                            }
+                    Some(Token::Literal(_)) => {
+                        let Some(Token::Literal(value)) = tokens.next() else {
+                            unreachable!()
                         };
-                        let &(before, _, _) = stack.peek()?;
-                        let goto = goto_Case(before)?;
-                        state = goto;
+                        state = State::State20;
                         stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Case),
-                            StackValue::NonTerm_Case(value),
+                            state,
+                            TokenType::Term(TokenKind::Literal),
+                            StackValue::Term_Literal(value),
                         );
                     }
                     _ => {
                         return Err(Error::UnexpectedToken {
-                            expected: vec![
-                                None,
-                                Some(Token::Pipe),
-                                Some(Token::Ident(Default::default())),
-                            ],
+                            expected: vec![Some(TokenKind::Literal)],
                             received: tokens.next(),
                             state_id: 12,
                             remaining_input: tokens.collect(),
@@ -916,90 +1456,16 @@ pub mod parser {
                 },
 
                 State::State13 => match tokens.peek() {
-                    Some(Token::Ident(_)) => {
-                        let v1 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::Term(Token::Ident(_)),
-                                    StackValue::Term_Ident(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Ident` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v0 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::Idents),
-                                    StackValue::NonTerm_Idents(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token Idents to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let value = { push(v0, v1) };
-                        let &(before, _, _) = stack.peek()?;
-                        let goto = goto_Idents(before)?;
-                        state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Idents),
-                            StackValue::NonTerm_Idents(value),
-                        );
-                    }
-                    Some(Token::Literal(_)) => {
-                        let v1 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::Term(Token::Ident(_)),
-                                    StackValue::Term_Ident(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Ident` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v0 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::Idents),
-                                    StackValue::NonTerm_Idents(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token Idents to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let value = { push(v0, v1) };
+                    None => {
+                        let value = reduce_0(&mut stack)?;
                         let &(before, _, _) = stack.peek()?;
-                        let goto = goto_Idents(before)?;
+                        let goto = goto_Grammar(before)?;
                         state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Idents),
-                            StackValue::NonTerm_Idents(value),
-                        );
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Grammar), value);
                     }
                     _ => {
                         return Err(Error::UnexpectedToken {
-                            expected: vec![
-                                Some(Token::Ident(Default::default())),
-                                Some(Token::Literal(Default::default())),
-                            ],
+                            expected: vec![None],
                             received: tokens.next(),
                             state_id: 13,
                             remaining_input: tokens.collect(),
@@ -1008,48 +1474,50 @@ pub mod parser {
                 },
 
                 State::State14 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_2(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Configs(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Configs), value);
+                    }
+                    Some(Token::Left(_)) => {
+                        let value = reduce_2(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Configs(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Configs), value);
+                    }
+                    Some(Token::Right(_)) => {
+                        let value = reduce_2(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Configs(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Configs), value);
+                    }
+                    Some(Token::NonAssoc(_)) => {
+                        let value = reduce_2(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Configs(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Configs), value);
+                    }
                     None => {
-                        let v1 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::Rules),
-                                    StackValue::NonTerm_Rules(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token Rules to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v0 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::Rule),
-                                    StackValue::NonTerm_Rule(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token Rule to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let value = { push(v1, v0) };
+                        let value = reduce_2(&mut stack)?;
                         let &(before, _, _) = stack.peek()?;
-                        let goto = goto_Rules(before)?;
+                        let goto = goto_Configs(before)?;
                         state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Rules),
-                            StackValue::NonTerm_Rules(value),
-                        );
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Configs), value);
                     }
                     _ => {
                         return Err(Error::UnexpectedToken {
-                            expected: vec![None],
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Left),
+                                Some(TokenKind::Right),
+                                Some(TokenKind::NonAssoc),
+                                None,
+                            ],
                             received: tokens.next(),
                             state_id: 14,
                             remaining_input: tokens.collect(),
@@ -1059,48 +1527,8 @@ pub mod parser {
 
                 State::State15 => match tokens.peek() {
                     None => {
-                        let v1 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::Rules),
-                                    StackValue::NonTerm_Rules(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token Rules to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v0 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::Configs),
-                                    StackValue::NonTerm_Configs(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token Configs to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let value = {
-                            Spec {
-                                rules: v1.into_iter().rev().collect(),
-                                configs: v0,
-                            }
-                        };
-                        let &(before, _, _) = stack.peek()?;
-                        let goto = goto_Grammar(before)?;
-                        state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Grammar),
-                            StackValue::NonTerm_Grammar(value),
-                        );
+                        let value = reduce_29(&mut stack)?;
+                        return Ok(value);
                     }
                     _ => {
                         return Err(Error::UnexpectedToken {
@@ -1113,87 +1541,14 @@ pub mod parser {
                 },
 
                 State::State16 => match tokens.peek() {
-                    Some(Token::Ident(_)) => {
-                        let v1 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::Config),
-                                    StackValue::NonTerm_Config(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token Config to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v0 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::Configs),
-                                    StackValue::NonTerm_Configs(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token Configs to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let value = { push(v0, v1) };
-                        let &(before, _, _) = stack.peek()?;
-                        let goto = goto_Configs(before)?;
-                        state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Configs),
-                            StackValue::NonTerm_Configs(value),
-                        );
-                    }
-                    None => {
-                        let v1 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::Config),
-                                    StackValue::NonTerm_Config(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token Config to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v0 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::Configs),
-                                    StackValue::NonTerm_Configs(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token Configs to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let value = { push(v0, v1) };
-                        let &(before, _, _) = stack.peek()?;
-                        let goto = goto_Configs(before)?;
-                        state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Configs),
-                            StackValue::NonTerm_Configs(value),
-                        );
+                    Some(Token::Colon) => {
+                        tokens.next();
+                        state = State::State12;
+                        stack.push(state, TokenType::Term(TokenKind::Colon), StackValue::None);
                     }
                     _ => {
                         return Err(Error::UnexpectedToken {
-                            expected: vec![None, Some(Token::Ident(Default::default()))],
+                            expected: vec![Some(TokenKind::Colon)],
                             received: tokens.next(),
                             state_id: 16,
                             remaining_input: tokens.collect(),
@@ -1202,19 +1557,16 @@ pub mod parser {
                 },
 
                 State::State17 => match tokens.peek() {
-                    Some(Token::Equals) => {
-                        let head = tokens.next().unwrap();
-                        state = State::State18;
-                        stack.push(state, TokenType::Term(head), StackValue::None);
-                    }
-                    Some(Token::Colon) => {
-                        let head = tokens.next().unwrap();
-                        state = State::State5;
-                        stack.push(state, TokenType::Term(head), StackValue::None);
+                    None => {
+                        let value = reduce_9(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Rules(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Rules), value);
                     }
                     _ => {
                         return Err(Error::UnexpectedToken {
-                            expected: vec![Some(Token::Colon), Some(Token::Equals)],
+                            expected: vec![None],
                             received: tokens.next(),
                             state_id: 17,
                             remaining_input: tokens.collect(),
@@ -1224,32 +1576,48 @@ pub mod parser {
 
                 State::State18 => match tokens.peek() {
                     Some(Token::Ident(_)) => {
-                        let head = tokens.next().unwrap();
-                        let Token::Ident(value) = head else {
-                            unreachable!()
-                        };
-                        let head = Token::Ident(Default::default());
-                        state = State::State20;
-                        stack.push(state, TokenType::Term(head), StackValue::Term_Ident(value));
+                        let value = reduce_4(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Config(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
                     }
-                    Some(Token::Literal(_)) => {
-                        let head = tokens.next().unwrap();
-                        let Token::Literal(value) = head else {
-                            unreachable!()
-                        };
-                        let head = Token::Literal(Default::default());
-                        state = State::State19;
-                        stack.push(
-                            state,
-                            TokenType::Term(head),
-                            StackValue::Term_Literal(value),
-                        );
+                    Some(Token::Left(_)) => {
+                        let value = reduce_4(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Config(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
+                    }
+                    Some(Token::Right(_)) => {
+                        let value = reduce_4(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Config(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
+                    }
+                    Some(Token::NonAssoc(_)) => {
+                        let value = reduce_4(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Config(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
+                    }
+                    None => {
+                        let value = reduce_4(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Config(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
                     }
                     _ => {
                         return Err(Error::UnexpectedToken {
                             expected: vec![
-                                Some(Token::Literal(Default::default())),
-                                Some(Token::Ident(Default::default())),
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Left),
+                                Some(TokenKind::Right),
+                                Some(TokenKind::NonAssoc),
+                                None,
                             ],
                             received: tokens.next(),
                             state_id: 18,
@@ -1260,106 +1628,49 @@ pub mod parser {
 
                 State::State19 => match tokens.peek() {
                     Some(Token::Ident(_)) => {
-                        let v2 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::Term(Token::Literal(_)),
-                                    StackValue::Term_Literal(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Literal` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v1 = {
-                            match stack.pop()? {
-                                (_, TokenType::Term(Token::Equals), StackValue::None) => (),
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Equals` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v0 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::Term(Token::Ident(_)),
-                                    StackValue::Term_Ident(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Ident` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let value = { (v0, v2) };
+                        let value = reduce_5(&mut stack)?;
                         let &(before, _, _) = stack.peek()?;
                         let goto = goto_Config(before)?;
                         state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Config),
-                            StackValue::NonTerm_Config(value),
-                        );
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
+                    }
+                    Some(Token::Left(_)) => {
+                        let value = reduce_5(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Config(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
+                    }
+                    Some(Token::Right(_)) => {
+                        let value = reduce_5(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Config(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
+                    }
+                    Some(Token::NonAssoc(_)) => {
+                        let value = reduce_5(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Config(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
                     }
                     None => {
-                        let v2 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::Term(Token::Literal(_)),
-                                    StackValue::Term_Literal(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Literal` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v1 = {
-                            match stack.pop()? {
-                                (_, TokenType::Term(Token::Equals), StackValue::None) => (),
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Equals` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v0 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::Term(Token::Ident(_)),
-                                    StackValue::Term_Ident(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Ident` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let value = { (v0, v2) };
+                        let value = reduce_5(&mut stack)?;
                         let &(before, _, _) = stack.peek()?;
                         let goto = goto_Config(before)?;
                         state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Config),
-                            StackValue::NonTerm_Config(value),
-                        );
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Config), value);
                     }
                     _ => {
                         return Err(Error::UnexpectedToken {
-                            expected: vec![Some(Token::Ident(Default::default())), None],
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Left),
+                                Some(TokenKind::Right),
+                                Some(TokenKind::NonAssoc),
+                                None,
+                            ],
                             received: tokens.next(),
                             state_id: 19,
                             remaining_input: tokens.collect(),
@@ -1369,106 +1680,27 @@ pub mod parser {
 
                 State::State20 => match tokens.peek() {
                     Some(Token::Ident(_)) => {
-                        let v2 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::Term(Token::Ident(_)),
-                                    StackValue::Term_Ident(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Ident` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v1 = {
-                            match stack.pop()? {
-                                (_, TokenType::Term(Token::Equals), StackValue::None) => (),
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Equals` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v0 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::Term(Token::Ident(_)),
-                                    StackValue::Term_Ident(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Ident` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let value = { (v0, v2) };
+                        let value = reduce_13(&mut stack)?;
                         let &(before, _, _) = stack.peek()?;
-                        let goto = goto_Config(before)?;
+                        let goto = goto_CaseList(before)?;
                         state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Config),
-                            StackValue::NonTerm_Config(value),
-                        );
+                        stack.push(goto, TokenType::NonTerm(NonTerm::CaseList), value);
+                    }
+                    Some(Token::Pipe) => {
+                        tokens.next();
+                        state = State::State21;
+                        stack.push(state, TokenType::Term(TokenKind::Pipe), StackValue::None);
                     }
                     None => {
-                        let v2 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::Term(Token::Ident(_)),
-                                    StackValue::Term_Ident(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Ident` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v1 = {
-                            match stack.pop()? {
-                                (_, TokenType::Term(Token::Equals), StackValue::None) => (),
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Equals` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let v0 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::Term(Token::Ident(_)),
-                                    StackValue::Term_Ident(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token `Ident` to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let value = { (v0, v2) };
+                        let value = reduce_13(&mut stack)?;
                         let &(before, _, _) = stack.peek()?;
-                        let goto = goto_Config(before)?;
+                        let goto = goto_CaseList(before)?;
                         state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Config),
-                            StackValue::NonTerm_Config(value),
-                        );
+                        stack.push(goto, TokenType::NonTerm(NonTerm::CaseList), value);
                     }
                     _ => {
                         return Err(Error::UnexpectedToken {
-                            expected: vec![None, Some(Token::Ident(Default::default()))],
+                            expected: vec![Some(TokenKind::Ident), Some(TokenKind::Pipe), None],
                             received: tokens.next(),
                             state_id: 20,
                             remaining_input: tokens.collect(),
@@ -1477,39 +1709,50 @@ pub mod parser {
                 },
 
                 State::State21 => match tokens.peek() {
-                    None => {
-                        let v0 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::Rules),
-                                    StackValue::NonTerm_Rules(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token Rules to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let value = {
-                            Spec {
-                                rules: v0.into_iter().rev().collect(),
-                                configs: Vec::new(),
-                            }
-                        };
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_17(&mut stack)?;
                         let &(before, _, _) = stack.peek()?;
-                        let goto = goto_Grammar(before)?;
+                        let goto = goto_Bindings(before)?;
                         state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Grammar),
-                            StackValue::NonTerm_Grammar(value),
-                        );
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Bindings), value);
+                    }
+                    Some(Token::Literal(_)) => {
+                        let value = reduce_17(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Bindings(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Bindings), value);
+                    }
+                    Some(Token::Prec) => {
+                        let value = reduce_17(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Bindings(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Bindings), value);
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_17(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Bindings(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Bindings), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_17(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Bindings(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Bindings), value);
                     }
                     _ => {
                         return Err(Error::UnexpectedToken {
-                            expected: vec![None],
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Literal),
+                                Some(TokenKind::Prec),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::SepBy),
+                            ],
                             received: tokens.next(),
                             state_id: 21,
                             remaining_input: tokens.collect(),
@@ -1519,58 +1762,22 @@ pub mod parser {
 
                 State::State22 => match tokens.peek() {
                     Some(Token::Ident(_)) => {
-                        let v0 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::Config),
-                                    StackValue::NonTerm_Config(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token Config to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let value = { vec![v0] };
+                        let value = reduce_11(&mut stack)?;
                         let &(before, _, _) = stack.peek()?;
-                        let goto = goto_Configs(before)?;
+                        let goto = goto_Rule(before)?;
                         state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Configs),
-                            StackValue::NonTerm_Configs(value),
-                        );
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Rule), value);
                     }
                     None => {
-                        let v0 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::Config),
-                                    StackValue::NonTerm_Config(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token Config to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        let value = { vec![v0] };
+                        let value = reduce_11(&mut stack)?;
                         let &(before, _, _) = stack.peek()?;
-                        let goto = goto_Configs(before)?;
+                        let goto = goto_Rule(before)?;
                         state = goto;
-                        stack.push(
-                            goto,
-                            TokenType::NonTerm(NonTerm::Configs),
-                            StackValue::NonTerm_Configs(value),
-                        );
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Rule), value);
                     }
                     _ => {
                         return Err(Error::UnexpectedToken {
-                            expected: vec![None, Some(Token::Ident(Default::default()))],
+                            expected: vec![Some(TokenKind::Ident), None],
                             received: tokens.next(),
                             state_id: 22,
                             remaining_input: tokens.collect(),
@@ -1579,13 +1786,28 @@ pub mod parser {
                 },
 
                 State::State23 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_13(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_CaseList(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::CaseList), value);
+                    }
+                    Some(Token::Pipe) => {
+                        tokens.next();
+                        state = State::State21;
+                        stack.push(state, TokenType::Term(TokenKind::Pipe), StackValue::None);
+                    }
                     None => {
-                        state = State::State24;
-                        stack.push(state, TokenType::TermEof, StackValue::None);
+                        let value = reduce_13(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_CaseList(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::CaseList), value);
                     }
                     _ => {
                         return Err(Error::UnexpectedToken {
-                            expected: vec![None],
+                            expected: vec![Some(TokenKind::Ident), Some(TokenKind::Pipe), None],
                             received: tokens.next(),
                             state_id: 23,
                             remaining_input: tokens.collect(),
@@ -1594,40 +1816,3609 @@ pub mod parser {
                 },
 
                 State::State24 => match tokens.peek() {
-                    None => {
-                        let v1 = {
-                            match stack.pop()? {
-                                (_, TokenType::TermEof, StackValue::None) => (),
-                                _ => {
-                                    return Err(Error::msg("expected token ＄ to be on the stack"))
-                                }
-                            }
-                        };
-                        let v0 = {
-                            match stack.pop()? {
-                                (
-                                    _,
-                                    TokenType::NonTerm(NonTerm::Grammar),
-                                    StackValue::NonTerm_Grammar(v),
-                                ) => v,
-                                _ => {
-                                    return Err(Error::msg(
-                                        "expected token Grammar to be on the stack",
-                                    ))
-                                }
-                            }
-                        };
-                        return Ok(v0);
+                    Some(Token::Ident(_)) => {
+                        let Some(Token::Ident(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State26;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Ident),
+                            StackValue::Term_Ident(value),
+                        );
+                    }
+                    Some(Token::Literal(_)) => {
+                        let Some(Token::Literal(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State27;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Literal),
+                            StackValue::Term_Literal(value),
+                        );
+                    }
+                    Some(Token::Prec) => {
+                        tokens.next();
+                        state = State::State28;
+                        stack.push(state, TokenType::Term(TokenKind::Prec), StackValue::None);
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let Some(Token::OpenParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State29;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::OpenParen),
+                            StackValue::Term_OpenParen(value),
+                        );
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let Some(Token::SepBy(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State30;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::SepBy),
+                            StackValue::Term_SepBy(value),
+                        );
                     }
                     _ => {
                         return Err(Error::UnexpectedToken {
-                            expected: vec![None],
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Literal),
+                                Some(TokenKind::Prec),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::SepBy),
+                            ],
                             received: tokens.next(),
                             state_id: 24,
                             remaining_input: tokens.collect(),
                         })
                     }
                 },
+
+                State::State25 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_12(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_CaseList(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::CaseList), value);
+                    }
+                    None => {
+                        let value = reduce_12(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_CaseList(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::CaseList), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![Some(TokenKind::Ident), None],
+                            received: tokens.next(),
+                            state_id: 25,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State26 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Literal(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Colon) => {
+                        tokens.next();
+                        state = State::State34;
+                        stack.push(state, TokenType::Term(TokenKind::Colon), StackValue::None);
+                    }
+                    Some(Token::Prec) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Question(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Literal),
+                                Some(TokenKind::Colon),
+                                Some(TokenKind::Prec),
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 26,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State27 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_14(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Case(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Case), value);
+                    }
+                    Some(Token::Pipe) => {
+                        let value = reduce_14(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Case(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Case), value);
+                    }
+                    None => {
+                        let value = reduce_14(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Case(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Case), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![Some(TokenKind::Ident), Some(TokenKind::Pipe), None],
+                            received: tokens.next(),
+                            state_id: 27,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State28 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let Some(Token::Ident(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State35;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Ident),
+                            StackValue::Term_Ident(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![Some(TokenKind::Ident)],
+                            received: tokens.next(),
+                            state_id: 28,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State29 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_21(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Items(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Items), value);
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_21(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Items(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Items), value);
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let value = reduce_21(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Items(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Items), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_21(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Items(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Items), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::CloseParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 29,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State30 => match tokens.peek() {
+                    Some(Token::OpenParen(_)) => {
+                        let Some(Token::OpenParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State37;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::OpenParen),
+                            StackValue::Term_OpenParen(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![Some(TokenKind::OpenParen)],
+                            received: tokens.next(),
+                            state_id: 30,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State31 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_16(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Bindings(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Bindings), value);
+                    }
+                    Some(Token::Literal(_)) => {
+                        let value = reduce_16(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Bindings(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Bindings), value);
+                    }
+                    Some(Token::Prec) => {
+                        let value = reduce_16(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Bindings(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Bindings), value);
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_16(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Bindings(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Bindings), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_16(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Bindings(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Bindings), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Literal),
+                                Some(TokenKind::Prec),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 31,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State32 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_18(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Binding(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Binding), value);
+                    }
+                    Some(Token::Literal(_)) => {
+                        let value = reduce_18(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Binding(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Binding), value);
+                    }
+                    Some(Token::Prec) => {
+                        let value = reduce_18(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Binding(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Binding), value);
+                    }
+                    Some(Token::Question(_)) => {
+                        let Some(Token::Question(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State38;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Question),
+                            StackValue::Term_Question(value),
+                        );
+                    }
+                    Some(Token::Star(_)) => {
+                        let Some(Token::Star(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State39;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Star),
+                            StackValue::Term_Star(value),
+                        );
+                    }
+                    Some(Token::Plus(_)) => {
+                        let Some(Token::Plus(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State40;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Plus),
+                            StackValue::Term_Plus(value),
+                        );
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_18(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Binding(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Binding), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_18(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Binding(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Binding), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Literal),
+                                Some(TokenKind::Prec),
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 32,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State33 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Literal(_)) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Prec) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Question(_)) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Literal),
+                                Some(TokenKind::Prec),
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 33,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State34 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let Some(Token::Ident(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State41;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Ident),
+                            StackValue::Term_Ident(value),
+                        );
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let Some(Token::OpenParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State29;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::OpenParen),
+                            StackValue::Term_OpenParen(value),
+                        );
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let Some(Token::SepBy(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State30;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::SepBy),
+                            StackValue::Term_SepBy(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 34,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State35 => match tokens.peek() {
+                    Some(Token::Literal(_)) => {
+                        let Some(Token::Literal(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State43;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Literal),
+                            StackValue::Term_Literal(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![Some(TokenKind::Literal)],
+                            received: tokens.next(),
+                            state_id: 35,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State36 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let Some(Token::Ident(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State44;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Ident),
+                            StackValue::Term_Ident(value),
+                        );
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let Some(Token::OpenParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State45;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::OpenParen),
+                            StackValue::Term_OpenParen(value),
+                        );
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let Some(Token::CloseParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State46;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::CloseParen),
+                            StackValue::Term_CloseParen(value),
+                        );
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let Some(Token::SepBy(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State47;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::SepBy),
+                            StackValue::Term_SepBy(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::CloseParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 36,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State37 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let Some(Token::Ident(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State50;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Ident),
+                            StackValue::Term_Ident(value),
+                        );
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let Some(Token::OpenParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State51;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::OpenParen),
+                            StackValue::Term_OpenParen(value),
+                        );
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let Some(Token::SepBy(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State52;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::SepBy),
+                            StackValue::Term_SepBy(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 37,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State38 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Literal(_)) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Prec) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Question(_)) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Literal),
+                                Some(TokenKind::Prec),
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 38,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State39 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Literal(_)) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Prec) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Question(_)) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Literal),
+                                Some(TokenKind::Prec),
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 39,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State40 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Literal(_)) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Prec) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Question(_)) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Literal),
+                                Some(TokenKind::Prec),
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 40,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State41 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Literal(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Prec) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Question(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Literal),
+                                Some(TokenKind::Prec),
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 41,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State42 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_19(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Binding(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Binding), value);
+                    }
+                    Some(Token::Literal(_)) => {
+                        let value = reduce_19(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Binding(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Binding), value);
+                    }
+                    Some(Token::Prec) => {
+                        let value = reduce_19(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Binding(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Binding), value);
+                    }
+                    Some(Token::Question(_)) => {
+                        let Some(Token::Question(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State38;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Question),
+                            StackValue::Term_Question(value),
+                        );
+                    }
+                    Some(Token::Star(_)) => {
+                        let Some(Token::Star(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State39;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Star),
+                            StackValue::Term_Star(value),
+                        );
+                    }
+                    Some(Token::Plus(_)) => {
+                        let Some(Token::Plus(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State40;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Plus),
+                            StackValue::Term_Plus(value),
+                        );
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_19(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Binding(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Binding), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_19(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Binding(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Binding), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Literal),
+                                Some(TokenKind::Prec),
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 42,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State43 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_15(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Case(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Case), value);
+                    }
+                    Some(Token::Pipe) => {
+                        let value = reduce_15(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Case(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Case), value);
+                    }
+                    None => {
+                        let value = reduce_15(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Case(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Case), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![Some(TokenKind::Ident), Some(TokenKind::Pipe), None],
+                            received: tokens.next(),
+                            state_id: 43,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State44 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Question(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::CloseParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 44,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State45 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_21(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Items(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Items), value);
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_21(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Items(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Items), value);
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let value = reduce_21(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Items(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Items), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_21(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Items(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Items), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::CloseParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 45,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State46 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Literal(_)) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Prec) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Question(_)) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Literal),
+                                Some(TokenKind::Prec),
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 46,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State47 => match tokens.peek() {
+                    Some(Token::OpenParen(_)) => {
+                        let Some(Token::OpenParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State56;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::OpenParen),
+                            StackValue::Term_OpenParen(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![Some(TokenKind::OpenParen)],
+                            received: tokens.next(),
+                            state_id: 47,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State48 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_20(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Items(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Items), value);
+                    }
+                    Some(Token::Question(_)) => {
+                        let Some(Token::Question(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State57;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Question),
+                            StackValue::Term_Question(value),
+                        );
+                    }
+                    Some(Token::Star(_)) => {
+                        let Some(Token::Star(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State58;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Star),
+                            StackValue::Term_Star(value),
+                        );
+                    }
+                    Some(Token::Plus(_)) => {
+                        let Some(Token::Plus(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State59;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Plus),
+                            StackValue::Term_Plus(value),
+                        );
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_20(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Items(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Items), value);
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let value = reduce_20(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Items(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Items), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_20(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Items(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Items), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::CloseParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 48,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State49 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Question(_)) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::CloseParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 49,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State50 => match tokens.peek() {
+                    Some(Token::Question(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Comma) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::Comma),
+                            ],
+                            received: tokens.next(),
+                            state_id: 50,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State51 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_21(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Items(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Items), value);
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_21(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Items(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Items), value);
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let value = reduce_21(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Items(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Items), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_21(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Items(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Items), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::CloseParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 51,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State52 => match tokens.peek() {
+                    Some(Token::OpenParen(_)) => {
+                        let Some(Token::OpenParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State61;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::OpenParen),
+                            StackValue::Term_OpenParen(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![Some(TokenKind::OpenParen)],
+                            received: tokens.next(),
+                            state_id: 52,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State53 => match tokens.peek() {
+                    Some(Token::Question(_)) => {
+                        let Some(Token::Question(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State62;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Question),
+                            StackValue::Term_Question(value),
+                        );
+                    }
+                    Some(Token::Star(_)) => {
+                        let Some(Token::Star(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State63;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Star),
+                            StackValue::Term_Star(value),
+                        );
+                    }
+                    Some(Token::Plus(_)) => {
+                        let Some(Token::Plus(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State64;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Plus),
+                            StackValue::Term_Plus(value),
+                        );
+                    }
+                    Some(Token::Comma) => {
+                        tokens.next();
+                        state = State::State65;
+                        stack.push(state, TokenType::Term(TokenKind::Comma), StackValue::None);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::Comma),
+                            ],
+                            received: tokens.next(),
+                            state_id: 53,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State54 => match tokens.peek() {
+                    Some(Token::Question(_)) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Comma) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::Comma),
+                            ],
+                            received: tokens.next(),
+                            state_id: 54,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State55 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let Some(Token::Ident(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State44;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Ident),
+                            StackValue::Term_Ident(value),
+                        );
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let Some(Token::OpenParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State45;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::OpenParen),
+                            StackValue::Term_OpenParen(value),
+                        );
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let Some(Token::CloseParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State66;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::CloseParen),
+                            StackValue::Term_CloseParen(value),
+                        );
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let Some(Token::SepBy(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State47;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::SepBy),
+                            StackValue::Term_SepBy(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::CloseParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 55,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State56 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let Some(Token::Ident(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State50;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Ident),
+                            StackValue::Term_Ident(value),
+                        );
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let Some(Token::OpenParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State51;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::OpenParen),
+                            StackValue::Term_OpenParen(value),
+                        );
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let Some(Token::SepBy(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State52;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::SepBy),
+                            StackValue::Term_SepBy(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 56,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State57 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Question(_)) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::CloseParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 57,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State58 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Question(_)) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::CloseParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 58,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State59 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Question(_)) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::CloseParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 59,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State60 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let Some(Token::Ident(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State44;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Ident),
+                            StackValue::Term_Ident(value),
+                        );
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let Some(Token::OpenParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State45;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::OpenParen),
+                            StackValue::Term_OpenParen(value),
+                        );
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let Some(Token::CloseParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State68;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::CloseParen),
+                            StackValue::Term_CloseParen(value),
+                        );
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let Some(Token::SepBy(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State47;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::SepBy),
+                            StackValue::Term_SepBy(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::CloseParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 60,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State61 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let Some(Token::Ident(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State50;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Ident),
+                            StackValue::Term_Ident(value),
+                        );
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let Some(Token::OpenParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State51;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::OpenParen),
+                            StackValue::Term_OpenParen(value),
+                        );
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let Some(Token::SepBy(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State52;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::SepBy),
+                            StackValue::Term_SepBy(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 61,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State62 => match tokens.peek() {
+                    Some(Token::Question(_)) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Comma) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::Comma),
+                            ],
+                            received: tokens.next(),
+                            state_id: 62,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State63 => match tokens.peek() {
+                    Some(Token::Question(_)) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Comma) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::Comma),
+                            ],
+                            received: tokens.next(),
+                            state_id: 63,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State64 => match tokens.peek() {
+                    Some(Token::Question(_)) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Comma) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::Comma),
+                            ],
+                            received: tokens.next(),
+                            state_id: 64,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State65 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let Some(Token::Ident(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State70;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Ident),
+                            StackValue::Term_Ident(value),
+                        );
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let Some(Token::OpenParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State71;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::OpenParen),
+                            StackValue::Term_OpenParen(value),
+                        );
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let Some(Token::SepBy(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State72;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::SepBy),
+                            StackValue::Term_SepBy(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 65,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State66 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Question(_)) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::CloseParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 66,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State67 => match tokens.peek() {
+                    Some(Token::Question(_)) => {
+                        let Some(Token::Question(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State62;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Question),
+                            StackValue::Term_Question(value),
+                        );
+                    }
+                    Some(Token::Star(_)) => {
+                        let Some(Token::Star(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State63;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Star),
+                            StackValue::Term_Star(value),
+                        );
+                    }
+                    Some(Token::Plus(_)) => {
+                        let Some(Token::Plus(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State64;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Plus),
+                            StackValue::Term_Plus(value),
+                        );
+                    }
+                    Some(Token::Comma) => {
+                        tokens.next();
+                        state = State::State75;
+                        stack.push(state, TokenType::Term(TokenKind::Comma), StackValue::None);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::Comma),
+                            ],
+                            received: tokens.next(),
+                            state_id: 67,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State68 => match tokens.peek() {
+                    Some(Token::Question(_)) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Comma) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::Comma),
+                            ],
+                            received: tokens.next(),
+                            state_id: 68,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State69 => match tokens.peek() {
+                    Some(Token::Question(_)) => {
+                        let Some(Token::Question(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State62;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Question),
+                            StackValue::Term_Question(value),
+                        );
+                    }
+                    Some(Token::Star(_)) => {
+                        let Some(Token::Star(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State63;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Star),
+                            StackValue::Term_Star(value),
+                        );
+                    }
+                    Some(Token::Plus(_)) => {
+                        let Some(Token::Plus(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State64;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Plus),
+                            StackValue::Term_Plus(value),
+                        );
+                    }
+                    Some(Token::Comma) => {
+                        tokens.next();
+                        state = State::State76;
+                        stack.push(state, TokenType::Term(TokenKind::Comma), StackValue::None);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::Comma),
+                            ],
+                            received: tokens.next(),
+                            state_id: 69,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State70 => match tokens.peek() {
+                    Some(Token::Question(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let value = reduce_26(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::CloseParen),
+                            ],
+                            received: tokens.next(),
+                            state_id: 70,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State71 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_21(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Items(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Items), value);
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_21(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Items(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Items), value);
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let value = reduce_21(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Items(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Items), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_21(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Items(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Items), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::CloseParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 71,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State72 => match tokens.peek() {
+                    Some(Token::OpenParen(_)) => {
+                        let Some(Token::OpenParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State78;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::OpenParen),
+                            StackValue::Term_OpenParen(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![Some(TokenKind::OpenParen)],
+                            received: tokens.next(),
+                            state_id: 72,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State73 => match tokens.peek() {
+                    Some(Token::Question(_)) => {
+                        let Some(Token::Question(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State79;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Question),
+                            StackValue::Term_Question(value),
+                        );
+                    }
+                    Some(Token::Star(_)) => {
+                        let Some(Token::Star(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State80;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Star),
+                            StackValue::Term_Star(value),
+                        );
+                    }
+                    Some(Token::Plus(_)) => {
+                        let Some(Token::Plus(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State81;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Plus),
+                            StackValue::Term_Plus(value),
+                        );
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let Some(Token::CloseParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State82;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::CloseParen),
+                            StackValue::Term_CloseParen(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::CloseParen),
+                            ],
+                            received: tokens.next(),
+                            state_id: 73,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State74 => match tokens.peek() {
+                    Some(Token::Question(_)) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let value = reduce_22(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::CloseParen),
+                            ],
+                            received: tokens.next(),
+                            state_id: 74,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State75 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let Some(Token::Ident(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State70;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Ident),
+                            StackValue::Term_Ident(value),
+                        );
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let Some(Token::OpenParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State71;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::OpenParen),
+                            StackValue::Term_OpenParen(value),
+                        );
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let Some(Token::SepBy(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State72;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::SepBy),
+                            StackValue::Term_SepBy(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 75,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State76 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let Some(Token::Ident(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State70;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Ident),
+                            StackValue::Term_Ident(value),
+                        );
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let Some(Token::OpenParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State71;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::OpenParen),
+                            StackValue::Term_OpenParen(value),
+                        );
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let Some(Token::SepBy(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State72;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::SepBy),
+                            StackValue::Term_SepBy(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 76,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State77 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let Some(Token::Ident(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State44;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Ident),
+                            StackValue::Term_Ident(value),
+                        );
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let Some(Token::OpenParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State45;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::OpenParen),
+                            StackValue::Term_OpenParen(value),
+                        );
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let Some(Token::CloseParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State85;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::CloseParen),
+                            StackValue::Term_CloseParen(value),
+                        );
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let Some(Token::SepBy(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State47;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::SepBy),
+                            StackValue::Term_SepBy(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::CloseParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 77,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State78 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let Some(Token::Ident(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State50;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Ident),
+                            StackValue::Term_Ident(value),
+                        );
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let Some(Token::OpenParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State51;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::OpenParen),
+                            StackValue::Term_OpenParen(value),
+                        );
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let Some(Token::SepBy(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State52;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::SepBy),
+                            StackValue::Term_SepBy(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 78,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State79 => match tokens.peek() {
+                    Some(Token::Question(_)) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let value = reduce_23(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::CloseParen),
+                            ],
+                            received: tokens.next(),
+                            state_id: 79,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State80 => match tokens.peek() {
+                    Some(Token::Question(_)) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let value = reduce_24(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::CloseParen),
+                            ],
+                            received: tokens.next(),
+                            state_id: 80,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State81 => match tokens.peek() {
+                    Some(Token::Question(_)) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let value = reduce_25(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Item(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Item), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::CloseParen),
+                            ],
+                            received: tokens.next(),
+                            state_id: 81,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State82 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Literal(_)) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Prec) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Question(_)) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Literal),
+                                Some(TokenKind::Prec),
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 82,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State83 => match tokens.peek() {
+                    Some(Token::Question(_)) => {
+                        let Some(Token::Question(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State79;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Question),
+                            StackValue::Term_Question(value),
+                        );
+                    }
+                    Some(Token::Star(_)) => {
+                        let Some(Token::Star(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State80;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Star),
+                            StackValue::Term_Star(value),
+                        );
+                    }
+                    Some(Token::Plus(_)) => {
+                        let Some(Token::Plus(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State81;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Plus),
+                            StackValue::Term_Plus(value),
+                        );
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let Some(Token::CloseParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State87;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::CloseParen),
+                            StackValue::Term_CloseParen(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::CloseParen),
+                            ],
+                            received: tokens.next(),
+                            state_id: 83,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State84 => match tokens.peek() {
+                    Some(Token::Question(_)) => {
+                        let Some(Token::Question(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State79;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Question),
+                            StackValue::Term_Question(value),
+                        );
+                    }
+                    Some(Token::Star(_)) => {
+                        let Some(Token::Star(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State80;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Star),
+                            StackValue::Term_Star(value),
+                        );
+                    }
+                    Some(Token::Plus(_)) => {
+                        let Some(Token::Plus(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State81;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Plus),
+                            StackValue::Term_Plus(value),
+                        );
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let Some(Token::CloseParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State88;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::CloseParen),
+                            StackValue::Term_CloseParen(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::CloseParen),
+                            ],
+                            received: tokens.next(),
+                            state_id: 84,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State85 => match tokens.peek() {
+                    Some(Token::Question(_)) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let value = reduce_27(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::CloseParen),
+                            ],
+                            received: tokens.next(),
+                            state_id: 85,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State86 => match tokens.peek() {
+                    Some(Token::Question(_)) => {
+                        let Some(Token::Question(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State62;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Question),
+                            StackValue::Term_Question(value),
+                        );
+                    }
+                    Some(Token::Star(_)) => {
+                        let Some(Token::Star(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State63;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Star),
+                            StackValue::Term_Star(value),
+                        );
+                    }
+                    Some(Token::Plus(_)) => {
+                        let Some(Token::Plus(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State64;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Plus),
+                            StackValue::Term_Plus(value),
+                        );
+                    }
+                    Some(Token::Comma) => {
+                        tokens.next();
+                        state = State::State89;
+                        stack.push(state, TokenType::Term(TokenKind::Comma), StackValue::None);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::Comma),
+                            ],
+                            received: tokens.next(),
+                            state_id: 86,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State87 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Question(_)) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::CloseParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 87,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State88 => match tokens.peek() {
+                    Some(Token::Question(_)) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Comma) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::Comma),
+                            ],
+                            received: tokens.next(),
+                            state_id: 88,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State89 => match tokens.peek() {
+                    Some(Token::Ident(_)) => {
+                        let Some(Token::Ident(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State70;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Ident),
+                            StackValue::Term_Ident(value),
+                        );
+                    }
+                    Some(Token::OpenParen(_)) => {
+                        let Some(Token::OpenParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State71;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::OpenParen),
+                            StackValue::Term_OpenParen(value),
+                        );
+                    }
+                    Some(Token::SepBy(_)) => {
+                        let Some(Token::SepBy(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State72;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::SepBy),
+                            StackValue::Term_SepBy(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Ident),
+                                Some(TokenKind::OpenParen),
+                                Some(TokenKind::SepBy),
+                            ],
+                            received: tokens.next(),
+                            state_id: 89,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State90 => match tokens.peek() {
+                    Some(Token::Question(_)) => {
+                        let Some(Token::Question(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State79;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Question),
+                            StackValue::Term_Question(value),
+                        );
+                    }
+                    Some(Token::Star(_)) => {
+                        let Some(Token::Star(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State80;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Star),
+                            StackValue::Term_Star(value),
+                        );
+                    }
+                    Some(Token::Plus(_)) => {
+                        let Some(Token::Plus(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State81;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::Plus),
+                            StackValue::Term_Plus(value),
+                        );
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let Some(Token::CloseParen(value)) = tokens.next() else {
+                            unreachable!()
+                        };
+                        state = State::State91;
+                        stack.push(
+                            state,
+                            TokenType::Term(TokenKind::CloseParen),
+                            StackValue::Term_CloseParen(value),
+                        );
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::CloseParen),
+                            ],
+                            received: tokens.next(),
+                            state_id: 90,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
+
+                State::State91 => match tokens.peek() {
+                    Some(Token::Question(_)) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Star(_)) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::Plus(_)) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    Some(Token::CloseParen(_)) => {
+                        let value = reduce_28(&mut stack)?;
+                        let &(before, _, _) = stack.peek()?;
+                        let goto = goto_Atom(before)?;
+                        state = goto;
+                        stack.push(goto, TokenType::NonTerm(NonTerm::Atom), value);
+                    }
+                    _ => {
+                        return Err(Error::UnexpectedToken {
+                            expected: vec![
+                                Some(TokenKind::Question),
+                                Some(TokenKind::Star),
+                                Some(TokenKind::Plus),
+                                Some(TokenKind::CloseParen),
+                            ],
+                            received: tokens.next(),
+                            state_id: 91,
+                            remaining_input: tokens.collect(),
+                        })
+                    }
+                },
             }
         }
     }
@@ -0,0 +1,435 @@
+//! The shorthands `X?`, `X*`, `X+`, `(A B)` and `sep_by(X, Comma)` in expansions. Each of them is
+//! replaced by a fresh rule, with a value that is built the same way for every use of it.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::bail;
+
+use crate::{
+    diagnostics::Diagnostic,
+    source::{Span, Spanned},
+};
+
+use super::ast::{Binding, Expansion, Item, Rule, Spec};
+
+/// How the types and values of the fresh rules are written in a target language. `{}` in a
+/// template stands for a type or a value.
+pub(super) struct Syntax {
+    /// the type of the value of terminals without data
+    unit: &'static str,
+    option: &'static str,
+    some: &'static str,
+    /// `some` for a `v0` of the unit type, which has to differ from `none`
+    some_unit: &'static str,
+    none: &'static str,
+    list: &'static str,
+    empty: &'static str,
+    /// a list of `v0`
+    single: &'static str,
+    /// `v0` with one more element added in constant time
+    push: &'static str,
+    /// The list `push` built, if it comes out reversed. Reversing it once in a rule of its own
+    /// keeps building it linear.
+    reverse: Option<&'static str>,
+    tuple_type: (&'static str, &'static str),
+    tuple: (&'static str, &'static str),
+}
+
+const RUST: Syntax = Syntax {
+    unit: "()",
+    option: "Option<{}>",
+    some: "Some(v0)",
+    some_unit: "Some(v0)",
+    none: "None",
+    list: "Vec<{}>",
+    empty: "Vec::new()",
+    single: "vec![v0]",
+    push: "{ let mut v0 = v0; v0.push({}); v0 }",
+    reverse: None,
+    tuple_type: ("({})", ", "),
+    tuple: ("({})", ", "),
+};
+
+const OCAML: Syntax = Syntax {
+    unit: "unit",
+    option: "({}) option",
+    some: "Some v0",
+    some_unit: "Some v0",
+    none: "None",
+    list: "({}) list",
+    empty: "[]",
+    single: "[v0]",
+    push: "{} :: v0",
+    reverse: Some("List.rev v0"),
+    tuple_type: ("({})", " * "),
+    tuple: ("({})", ", "),
+};
+
+/// python ignores the types, but they are still written down like type hints
+const PYTHON: Syntax = Syntax {
+    unit: "None",
+    option: "Optional[{}]",
+    some: "v0",
+    some_unit: "True",
+    none: "None",
+    list: "list[{}]",
+    empty: "[]",
+    single: "[v0]",
+    push: "(v0.append({}), v0)[1]",
+    reverse: None,
+    tuple_type: ("tuple[{}]", ", "),
+    tuple: ("({})", ", "),
+};
+
+impl Syntax {
+    pub(super) fn of(target: &str) -> Option<&'static Syntax> {
+        match target {
+            "rust" => Some(&RUST),
+            "ocaml" => Some(&OCAML),
+            "python" => Some(&PYTHON),
+            _ => None,
+        }
+    }
+}
+
+fn fill(template: &str, value: &str) -> String {
+    template.replacen("{}", value, 1)
+}
+
+/// Replaces every shorthand in the expansions of `spec` by a fresh rule and adds those rules to
+/// `spec`. The same shorthand always stands for the same rule, two rules for it would only
+/// conflict with each other. Terminals carry the data `term_types` declares for them.
+pub(super) fn desugar(
+    spec: &mut Spec,
+    syntax: &Syntax,
+    term_types: &HashMap<String, String>,
+) -> anyhow::Result<()> {
+    let mut desugar = Desugar {
+        syntax,
+        term_types,
+        types: spec
+            .rules
+            .iter()
+            .map(|x| (x.name.value.clone(), x.typ.value.clone()))
+            .collect(),
+        taken: HashSet::new(),
+        fresh: HashMap::new(),
+        rules: Vec::new(),
+    };
+    for rule in &spec.rules {
+        desugar.taken.insert(rule.name.value.clone());
+        for item in rule.expansions.iter().flat_map(|x| &x.items) {
+            let symbols = item.item.symbols();
+            desugar
+                .taken
+                .extend(symbols.iter().map(|x| x.value.to_owned()));
+        }
+    }
+
+    for rule in &mut spec.rules {
+        for item in rule.expansions.iter_mut().flat_map(|x| &mut x.items) {
+            let item = &mut item.item;
+            if !matches!(item.value, Item::Symbol(_)) {
+                *item = Spanned::new(Item::Symbol(desugar.symbol(item)?), item.span);
+            }
+        }
+    }
+    spec.rules.extend(desugar.rules);
+    Ok(())
+}
+
+struct Desugar<'a> {
+    syntax: &'a Syntax,
+    term_types: &'a HashMap<String, String>,
+    /// the types of all rules, including the fresh ones
+    types: HashMap<String, String>,
+    /// the names of all rules and terminals
+    taken: HashSet<String>,
+    /// the names of the fresh rules by the shorthand they stand for
+    fresh: HashMap<String, String>,
+    rules: Vec<Rule>,
+}
+
+impl Desugar<'_> {
+    /// the type of the values of a rule or terminal
+    fn type_of(&self, symbol: &str) -> String {
+        self.types
+            .get(symbol)
+            .or(self.term_types.get(symbol))
+            .map_or(self.syntax.unit, |x| x.trim())
+            .to_owned()
+    }
+
+    /// the symbol that stands for `item`, adding fresh rules as needed
+    fn symbol(&mut self, item: &Spanned<Item>) -> anyhow::Result<String> {
+        let syntax = self.syntax;
+        match &item.value {
+            Item::Symbol(name) => Ok(name.clone()),
+            Item::Group(items) if items.is_empty() => {
+                bail!(Diagnostic::error("empty group")
+                    .label(item.span, "a group needs at least one symbol"))
+            }
+            Item::Group(items) if items.len() == 1 => self.symbol(&items[0]),
+            Item::Optional(x) => {
+                let x = self.symbol(x)?;
+                let some = match self.type_of(&x) == syntax.unit {
+                    true => syntax.some_unit,
+                    false => syntax.some,
+                };
+                let typ = fill(syntax.option, &self.type_of(&x));
+                let name = format!("{x}_opt");
+                Ok(self.rule(item.span, format!("{x}?"), name, typ, |_| {
+                    vec![
+                        (vec![x.clone()], some.to_owned()),
+                        (vec![], syntax.none.to_owned()),
+                    ]
+                }))
+            }
+            Item::Star(x) => {
+                let x = self.symbol(x)?;
+                let typ = fill(syntax.list, &self.type_of(&x));
+                let name = format!("{x}_star");
+                Ok(self.list(item.span, format!("{x}*"), name, typ, |name| {
+                    vec![
+                        (vec![], syntax.empty.to_owned()),
+                        (vec![name.to_owned(), x.clone()], fill(syntax.push, "v1")),
+                    ]
+                }))
+            }
+            Item::Plus(x) => {
+                let x = self.symbol(x)?;
+                let typ = fill(syntax.list, &self.type_of(&x));
+                let name = format!("{x}_plus");
+                Ok(self.plus(item.span, format!("{x}+"), name, typ, x, None))
+            }
+            Item::Group(items) => {
+                let symbols = items
+                    .iter()
+                    .map(|x| self.symbol(x))
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                let types = symbols.iter().map(|x| self.type_of(x)).collect::<Vec<_>>();
+                let typ = fill(syntax.tuple_type.0, &types.join(syntax.tuple_type.1));
+                let values = (0..symbols.len())
+                    .map(|i| format!("v{i}"))
+                    .collect::<Vec<_>>();
+                let value = fill(syntax.tuple.0, &values.join(syntax.tuple.1));
+                let key = format!("({})", symbols.join(" "));
+                let name = format!("{}_group", symbols.join("_"));
+                Ok(self.rule(item.span, key, name, typ, |_| vec![(symbols, value)]))
+            }
+            Item::SepBy(x, separator) => {
+                let (x, separator) = (self.symbol(x)?, self.symbol(separator)?);
+                let typ = fill(syntax.list, &self.type_of(&x));
+                let name = format!("{x}_sep_by_{separator}");
+                let plus = self.plus(
+                    item.span,
+                    format!("sep_by({x}, {separator})+"),
+                    format!("{name}_plus"),
+                    typ.clone(),
+                    x.clone(),
+                    Some(separator.clone()),
+                );
+                let key = format!("sep_by({x}, {separator})");
+                Ok(self.rule(item.span, key, name, typ, |_| {
+                    vec![
+                        (vec![], syntax.empty.to_owned()),
+                        (vec![plus], "v0".to_owned()),
+                    ]
+                }))
+            }
+        }
+    }
+
+    /// at least one `x`, with a `separator` between each two of them if given
+    fn plus(
+        &mut self,
+        span: Span,
+        key: String,
+        name: String,
+        typ: String,
+        x: String,
+        separator: Option<String>,
+    ) -> String {
+        let syntax = self.syntax;
+        self.list(span, key, name, typ, |name| {
+            let mut more = vec![name.to_owned()];
+            more.extend(separator);
+            more.push(x.clone());
+            let push = fill(syntax.push, &format!("v{}", more.len() - 1));
+            vec![(vec![x], syntax.single.to_owned()), (more, push)]
+        })
+    }
+
+    /// Like [`Desugar::rule`] for a list that `expansions` builds with `push`. If that builds it
+    /// in reverse, the list is built by the rule `{name}_rev` and reversed by the rule `name`.
+    fn list<F>(
+        &mut self,
+        span: Span,
+        key: String,
+        name: String,
+        typ: String,
+        expansions: F,
+    ) -> String
+    where
+        F: FnOnce(&str) -> Vec<(Vec<String>, String)>,
+    {
+        let Some(reverse) = self.syntax.reverse else {
+            return self.rule(span, key, name, typ, expansions);
+        };
+        let reversed = self.rule(
+            span,
+            format!("{key} reversed"),
+            format!("{name}_rev"),
+            typ.clone(),
+            expansions,
+        );
+        self.rule(span, key, name, typ, |_| {
+            vec![(vec![reversed], reverse.to_owned())]
+        })
+    }
+
+    /// The name of the rule for the shorthand `key`. If there is none yet, it is added with the
+    /// productions `expansions` returns when given its name. `name` is only a suggestion, it may
+    /// already be taken.
+    fn rule<F>(
+        &mut self,
+        span: Span,
+        key: String,
+        mut name: String,
+        typ: String,
+        expansions: F,
+    ) -> String
+    where
+        F: FnOnce(&str) -> Vec<(Vec<String>, String)>,
+    {
+        if let Some(name) = self.fresh.get(&key) {
+            return name.clone();
+        }
+        while self.taken.contains(&name) {
+            name.push('_');
+        }
+        self.taken.insert(name.clone());
+        self.fresh.insert(key, name.clone());
+        self.types.insert(name.clone(), typ.clone());
+
+        let expansions = expansions(&name)
+            .into_iter()
+            .map(|(symbols, code)| {
+                let items = symbols
+                    .into_iter()
+                    .map(|x| Binding::new(None, Spanned::new(Item::Symbol(x), span)))
+                    .collect();
+                Expansion::new(items, Spanned::new(code, span), None)
+            })
+            .collect();
+        self.rules.push(Rule {
+            name: Spanned::new(name.clone(), span),
+            typ: Spanned::new(typ, span),
+            expansions,
+            span,
+        });
+        name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::Source;
+
+    fn desugared(input: &str) -> anyhow::Result<Spec> {
+        desugared_for(input, &RUST)
+    }
+
+    fn desugared_for(input: &str, syntax: &Syntax) -> anyhow::Result<Spec> {
+        let tokens = super::super::lex(Source::new("test.ast", input))?;
+        let mut spec = super::super::parser::parse(tokens.into_iter().map(|x| x.0)).unwrap();
+        let term_types = HashMap::from([("Int".to_owned(), "i32".to_owned())]);
+        desugar(&mut spec, syntax, &term_types)?;
+        Ok(spec)
+    }
+
+    /// the name, type and productions of every rule
+    fn rules(spec: &Spec) -> Vec<(String, String, Vec<String>)> {
+        spec.rules
+            .iter()
+            .map(|rule| {
+                let productions = rule.expansions.iter().map(|x| {
+                    let symbols = x.items.iter().map(|x| match &x.item.value {
+                        Item::Symbol(name) => name.as_str(),
+                        _ => panic!("shorthand left after desugaring"),
+                    });
+                    symbols.collect::<Vec<_>>().join(" ")
+                });
+                (
+                    rule.name.value.clone(),
+                    rule.typ.value.clone(),
+                    productions.collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn shorthands_become_typed_rules() {
+        let input = "Prog: { () }\n  | Expr* Semi? sep_by(Int, Comma) (Plus Int)+ { () }\nExpr: { i32 }\n  | Int { v0 }\n";
+        let rules = rules(&desugared(input).unwrap());
+        let rule = |name: &str| rules.iter().find(|x| x.0 == name).unwrap();
+        assert_eq!(
+            rule("Prog").2,
+            ["Expr_star Semi_opt Int_sep_by_Comma Plus_Int_group_plus"]
+        );
+        assert_eq!(rule("Expr_star").1, "Vec<i32>");
+        assert_eq!(rule("Expr_star").2, ["", "Expr_star Expr"]);
+        assert_eq!(rule("Semi_opt").1, "Option<()>");
+        assert_eq!(rule("Semi_opt").2, ["Semi", ""]);
+        assert_eq!(rule("Int_sep_by_Comma").1, "Vec<i32>");
+        assert_eq!(rule("Int_sep_by_Comma").2, ["", "Int_sep_by_Comma_plus"]);
+        assert_eq!(
+            rule("Int_sep_by_Comma_plus").2,
+            ["Int", "Int_sep_by_Comma_plus Comma Int"]
+        );
+        assert_eq!(rule("Plus_Int_group").1, "((), i32)");
+        assert_eq!(rule("Plus_Int_group_plus").1, "Vec<((), i32)>");
+    }
+
+    #[test]
+    fn ocaml_lists_are_reversed_once() {
+        let input = "Prog: { unit }\n  | Int* sep_by(Int, Comma) { () }\n";
+        let spec = desugared_for(input, &OCAML).unwrap();
+        let code = |name: &str| {
+            let rule = spec.rules.iter().find(|x| x.name.value == name).unwrap();
+            rule.expansions
+                .iter()
+                .map(|x| x.code.value.as_str())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(code("Int_star_rev"), ["[]", "v1 :: v0"]);
+        assert_eq!(code("Int_star"), ["List.rev v0"]);
+        assert_eq!(code("Int_sep_by_Comma_plus_rev"), ["[v0]", "v2 :: v0"]);
+        assert_eq!(code("Int_sep_by_Comma_plus"), ["List.rev v0"]);
+        assert_eq!(code("Int_sep_by_Comma"), ["[]", "v0"]);
+    }
+
+    #[test]
+    fn shorthands_share_rules() {
+        let input = "Prog: { () }\n  | Int* Semi { () }\n  | Int* Colon Int* { () }\n  | (Int) { () }\nInt_star: { () }\n  | Semi { () }\n";
+        let rules = rules(&desugared(input).unwrap());
+        let names: Vec<_> = rules.iter().map(|x| x.0.as_str()).collect();
+        assert_eq!(names, ["Prog", "Int_star", "Int_star_"]);
+        assert_eq!(
+            rules[0].2,
+            ["Int_star_ Semi", "Int_star_ Colon Int_star_", "Int"]
+        );
+    }
+
+    #[test]
+    fn empty_groups() {
+        let input = "Prog: { () }\n  | Int () { () }\n";
+        let error = desugared(input).err().unwrap();
+        let diagnostic = error.downcast_ref::<Diagnostic>().unwrap();
+        assert_eq!(diagnostic.message, "empty group");
+        let span = diagnostic.labels[0].span;
+        assert_eq!(&input[span.start..span.end], "()");
+    }
+}
@@ -1,6 +1,11 @@
+use std::collections::HashMap;
+
+/// Interns strings. Ids are handed out in insertion order and never change, the reverse index
+/// makes lookups by string O(1).
 #[derive(Debug, Clone, Default)]
 pub struct Pool {
     inner: Vec<String>,
+    index: HashMap<String, Id>,
 }
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -8,8 +13,8 @@ pub struct Id(usize);
 
 impl Pool {
     /// constructs a new, empty Pool
-    pub const fn new() -> Self {
-        Self { inner: Vec::new() }
+    pub fn new() -> Self {
+        Self::default()
     }
 
     pub fn add<S>(&mut self, string: S) -> Id
@@ -17,27 +22,41 @@ impl Pool {
         S: ToOwned<Owned = String>,
     {
         let string = string.to_owned();
-        if let Some((index, _)) = self.inner.iter().enumerate().find(|&(_, s)| s == &string) {
-            return Id(index);
+        if let Some(&id) = self.index.get(&string) {
+            return id;
         }
 
-        self.inner.push(string);
-        Id(self.inner.len() - 1)
+        let id = Id(self.inner.len());
+        self.inner.push(string.clone());
+        self.index.insert(string, id);
+        id
     }
 
     pub fn get_reverse<S>(&self, string: S) -> Option<Id>
     where
         S: AsRef<str>,
     {
-        let string = string.as_ref();
-        self.inner
-            .iter()
-            .enumerate()
-            .find(|x| x.1 == string)
-            .map(|x| Id(x.0))
+        self.index.get(string.as_ref()).copied()
     }
 
     pub fn get(&self, id: Id) -> &str {
         &self.inner[id.0]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_are_stable() {
+        let mut pool = Pool::new();
+        let a = pool.add("a".to_owned());
+        let b = pool.add("b".to_owned());
+        assert_eq!(pool.add("a".to_owned()), a);
+        assert!(a < b);
+        assert_eq!(pool.get_reverse("b"), Some(b));
+        assert_eq!(pool.get_reverse("c"), None);
+        assert_eq!(pool.get(b), "b");
+    }
+}
@@ -1,11 +1,14 @@
-use std::{collections::HashMap, fmt::Display};
+use std::{collections::BTreeMap, fmt::Display};
 
 use either::Either;
+use itertools::Itertools;
 use tracing::info;
 
 use crate::{
+    counterexample::Counterexample,
+    diagnostics::Diagnostic,
     generator::{Graph, Uid},
-    grammar::Token,
+    grammar::{Grammar, ReduceReduce, Resolution, Token},
     string_pool::{Id, Pool},
 };
 
@@ -17,6 +20,113 @@ pub struct Conflict {
     pub state: Uid,
 }
 
+impl Conflict {
+    pub fn is_shift_reduce(&self) -> bool {
+        matches!(self.either, Action::Shift(_)) || matches!(self.or, Action::Shift(_))
+    }
+}
+
+/// All conflicts that were found while constructing a table, ordered by state and token
+#[derive(Debug, Clone)]
+pub struct Conflicts(pub Vec<Conflict>);
+
+impl Conflicts {
+    pub fn shift_reduce(&self) -> usize {
+        self.0.iter().filter(|x| x.is_shift_reduce()).count()
+    }
+
+    pub fn reduce_reduce(&self) -> usize {
+        self.0.len() - self.shift_reduce()
+    }
+
+    /// One error per state and token that has conflicting actions, labelling the productions
+    /// involved and followed by the counterexamples. `examples` has to be in the same order as
+    /// the conflicts, but may be shorter.
+    pub fn diagnostics(
+        &self,
+        graph: &Graph,
+        grammar: &Grammar,
+        examples: &[Counterexample],
+    ) -> Vec<Diagnostic> {
+        let pool = grammar.pool();
+        let mut diagnostics = Vec::new();
+        for ((state, token), group) in &self
+            .0
+            .iter()
+            .enumerate()
+            .group_by(|(_, x)| (x.state, x.token))
+        {
+            let mut diagnostic = Diagnostic::error(format!(
+                "conflict in state {state} on token {}",
+                token.display(pool)
+            ));
+            let group = group.collect_vec();
+            let actions = group
+                .iter()
+                .flat_map(|(_, x)| [&x.either, &x.or])
+                .sorted()
+                .dedup()
+                .collect_vec();
+
+            // reductions come first, so the first of them is the primary label
+            let mut spans = Vec::new();
+            for action in &actions {
+                match action {
+                    Action::Reduce(..) => {
+                        let index = production_index(grammar, action);
+                        let message = format!("reduced on {}", token.display(pool));
+                        spans.push((grammar.entries()[index].span(), message));
+                    }
+                    Action::Shift(_) => {
+                        for item in graph.0[&state].0.iter() {
+                            if item.after.first() != Some(&token) {
+                                continue;
+                            }
+                            let production = [&item.before[..], &item.after[..]].concat();
+                            let index = grammar
+                                .entry_index(item.rule, &production)
+                                .expect("items refer to productions of the grammar");
+                            let message = format!("shifts {}", token.display(pool));
+                            spans.push((grammar.entries()[index].span(), message));
+                        }
+                    }
+                }
+            }
+            for (i, (span, message)) in spans.into_iter().dedup().enumerate() {
+                if let Some(span) = span {
+                    diagnostic = match i {
+                        0 => diagnostic.label(span, message),
+                        _ => diagnostic.secondary(span, message),
+                    };
+                }
+            }
+
+            for (i, action) in actions.into_iter().enumerate() {
+                let prefix = if i == 0 { "either:" } else { "or:    " };
+                diagnostic = diagnostic.note(format!("{prefix} {}", action.display(pool, token)));
+            }
+            for (i, _) in group {
+                if let Some(example) = examples.get(i) {
+                    let example = textwrap::dedent(&example.display(pool).to_string());
+                    diagnostic = diagnostic.note(example.trim_end());
+                }
+            }
+            diagnostics.push(diagnostic);
+        }
+        diagnostics
+    }
+
+    /// how many conflicts of each kind there are
+    pub fn summary(&self) -> String {
+        format!(
+            "found {} conflicts ({} shift/reduce, {} reduce/reduce)",
+            self.0.len(),
+            self.shift_reduce(),
+            self.reduce_reduce()
+        )
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Action {
     Reduce(Id, Vec<Token>),
@@ -26,11 +136,18 @@ pub enum Action {
 pub struct ActionDisplay<'a> {
     action: &'a Action,
     pool: &'a Pool,
+    token: Token,
 }
 
 impl Action {
-    pub fn display<'a>(&'a self, pool: &'a Pool) -> ActionDisplay<'a> {
-        ActionDisplay { action: self, pool }
+    /// The action as it is taken on `token`. Shifts are shown by the token they shift, the state
+    /// they go to means nothing to the user.
+    pub fn display<'a>(&'a self, pool: &'a Pool, token: Token) -> ActionDisplay<'a> {
+        ActionDisplay {
+            action: self,
+            pool,
+            token,
+        }
     }
 }
 
@@ -44,25 +161,29 @@ impl Display for ActionDisplay<'_> {
                 }
                 Ok(())
             }
-            Action::Shift(x) => write!(f, "s{}", x),
+            Action::Shift(_) => write!(f, "shift {}", self.token.display(self.pool)),
         }
     }
 }
 
 #[derive(Debug)]
 pub struct TableEntry {
-    pub(crate) actions: HashMap<Token, Action>,
-    pub(crate) gotos: HashMap<Id, Uid>,
+    pub(crate) actions: BTreeMap<Token, Action>,
+    pub(crate) gotos: BTreeMap<Id, Uid>,
 }
 
 #[derive(Debug)]
-pub struct Table(pub(crate) HashMap<Uid, TableEntry>);
+pub struct Table(pub(crate) BTreeMap<Uid, TableEntry>);
 
 impl Table {
-    #[tracing::instrument(skip(graph))]
-    pub fn from_graph(graph: &Graph) -> Result<Self, Conflict> {
+    /// constructs the parse table. Shift/reduce conflicts are resolved using the precedence
+    /// declarations of `grammar` where possible, reduce/reduce conflicts only if the grammar opts
+    /// into [`ReduceReduce::Earlier`]. All remaining conflicts are reported at once.
+    #[tracing::instrument(skip(graph, grammar))]
+    pub fn from_graph(graph: &Graph, grammar: &Grammar) -> Result<Self, Conflicts> {
         info!("constructing table");
-        let mut table = HashMap::new();
+        let mut table = BTreeMap::new();
+        let mut conflicts = Vec::new();
 
         for (&state_id, (states, neighbors)) in &graph.0 {
             let shifts = neighbors.iter().filter_map(|(a, &b)| {
@@ -88,27 +209,54 @@ impl Table {
                 )
             });
 
-            let mut reductions: HashMap<Token, Action> = HashMap::new();
-            for reduction in reductions_iter {
-                // if let Some(conflict) = reductions.get(&reduction.0) {
-                //     return Err(Conflict {
-                //         token: reduction.0,
-                //         either: reduction.1,
-                //         or: conflict.clone(),
-                //         state: state_id,
-                //     });
-                // }
-                reductions.insert(reduction.0, reduction.1.clone());
+            let mut reductions: BTreeMap<Token, Action> = BTreeMap::new();
+            for (k, reduce) in reductions_iter {
+                if let Some(conflict) = reductions.get(&k) {
+                    if *conflict == reduce {
+                        continue;
+                    }
+                    match grammar.reduce_reduce() {
+                        ReduceReduce::Earlier => {
+                            if production_index(grammar, conflict)
+                                < production_index(grammar, &reduce)
+                            {
+                                continue;
+                            }
+                        }
+                        ReduceReduce::Error => {
+                            conflicts.push(Conflict {
+                                either: reduce,
+                                token: k,
+                                or: conflict.clone(),
+                                state: state_id,
+                            });
+                            continue;
+                        }
+                    }
+                }
+                reductions.insert(k, reduce);
             }
 
             for (k, shift) in shifts {
                 if let Some(conflict) = reductions.get(&k) {
-                    return Err(Conflict {
-                        either: shift,
-                        token: k,
-                        or: conflict.clone(),
-                        state: state_id,
-                    });
+                    let entry = &grammar.entries()[production_index(grammar, conflict)];
+                    match grammar.resolve(entry, k) {
+                        Some(Resolution::Reduce) => continue,
+                        Some(Resolution::Error) => {
+                            reductions.remove(&k);
+                            continue;
+                        }
+                        Some(Resolution::Shift) => {}
+                        None => {
+                            conflicts.push(Conflict {
+                                either: shift,
+                                token: k,
+                                or: conflict.clone(),
+                                state: state_id,
+                            });
+                            continue;
+                        }
+                    }
                 }
                 reductions.insert(k, shift);
             }
@@ -122,7 +270,107 @@ impl Table {
             );
         }
 
+        if !conflicts.is_empty() {
+            conflicts.sort_by_key(|x: &Conflict| (x.state, x.token));
+            return Err(Conflicts(conflicts));
+        }
+
         info!("table has {} states", table.len());
         Ok(Table(table))
     }
 }
+
+fn production_index(grammar: &Grammar, reduce: &Action) -> usize {
+    let Action::Reduce(rule, expansion) = reduce else {
+        unreachable!("only reductions refer to productions")
+    };
+    grammar
+        .entry_index(*rule, expansion)
+        .expect("reduction refers to a production of the grammar")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar::GrammarBuilder;
+
+    fn table(g: &Grammar) -> Result<Table, Conflicts> {
+        let graph = Graph::make(g);
+        Table::from_graph(&graph, g)
+    }
+
+    /// `S -> A | B`, `A -> x`, `B -> x` in exactly that order
+    fn ambiguous() -> GrammarBuilder {
+        let s = String::from;
+        let mut builder = Grammar::builder();
+        for (rule, non_term) in [("S", "A"), ("S", "B")] {
+            let prod = builder.prod_builder().non_term(s(non_term)).finish();
+            builder = builder.production(s(rule), prod, s(""));
+        }
+        for rule in ["A", "B"] {
+            let prod = builder.prod_builder().term(s("x")).finish();
+            builder = builder.production(s(rule), prod, s(""));
+        }
+        builder
+    }
+
+    fn reduces(table: &Table, g: &Grammar, rule: &str) -> bool {
+        let rule = g.pool().get_reverse(rule).unwrap();
+        table
+            .0
+            .values()
+            .flat_map(|x| x.actions.values())
+            .any(|x| matches!(x, Action::Reduce(r, _) if *r == rule))
+    }
+
+    #[test]
+    fn reduce_reduce_is_reported() {
+        let g = ambiguous().finish("S".to_owned());
+        let conflicts = table(&g).unwrap_err();
+        assert_eq!(conflicts.0.len(), 1);
+        let conflict = conflicts.0[0].clone();
+        let a = g.pool().get_reverse("A").unwrap();
+        let b = g.pool().get_reverse("B").unwrap();
+        let x = Token::Term(g.pool().get_reverse("x").unwrap());
+        assert_eq!(conflict.token, Token::Eof);
+        let mut actions = [conflict.either, conflict.or];
+        actions.sort();
+        assert_eq!(
+            actions,
+            [Action::Reduce(a, vec![x]), Action::Reduce(b, vec![x])]
+        );
+    }
+
+    #[test]
+    fn reduce_reduce_prefers_earlier() {
+        let g = ambiguous()
+            .reduce_reduce(ReduceReduce::Earlier)
+            .finish("S".to_owned());
+        let table = table(&g).unwrap();
+        assert!(reduces(&table, &g, "A"));
+        assert!(!reduces(&table, &g, "B"));
+    }
+
+    #[test]
+    fn all_conflicts_are_reported() {
+        let g = crate::grammar!(
+            E:
+            E => N "E" T "plus" N "E" @ "";
+            E => N "E" T "times" N "E" @ "";
+            E => T "x" @ "";
+        );
+        let conflicts = table(&g).unwrap_err();
+        assert_eq!(conflicts.shift_reduce(), 4);
+        assert_eq!(conflicts.reduce_reduce(), 0);
+        assert_eq!(
+            conflicts.summary(),
+            "found 4 conflicts (4 shift/reduce, 0 reduce/reduce)"
+        );
+
+        let graph = Graph::make(&g);
+        let diagnostics = conflicts.diagnostics(&graph, &g, &[]);
+        let notes = &diagnostics[0].notes;
+        assert_eq!(notes[0], "either: E -> E `plus` E");
+        assert_eq!(notes[1], "or:     shift `plus`");
+    }
+}
@@ -1,3 +1,5 @@
+#![allow(dead_code)]
+
 use std::{path::Path, process::Command};
 
 use tempdir::TempDir;
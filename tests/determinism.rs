@@ -0,0 +1,42 @@
+use std::path::Path;
+
+use common::{asterisk, build_dir};
+
+use crate::common::CommandExt;
+
+mod common;
+
+/// generates `source` into `output` with the given algorithm
+fn generate(source: &str, algorithm: &str, output: &Path) -> anyhow::Result<String> {
+    asterisk()
+        .arg("--algorithm")
+        .arg(algorithm)
+        .arg("--output")
+        .arg(output)
+        .arg(source)
+        .run()?;
+    Ok(std::fs::read_to_string(output)?)
+}
+
+/// Every run uses differently seeded hash maps, so generating the same grammar twice catches
+/// iteration order leaking into the output.
+#[test]
+fn output_is_deterministic() -> anyhow::Result<()> {
+    let build = build_dir();
+    for source in [
+        "./src/spec/spec.ast",
+        "./tests/frontends/rust/precedence.ast",
+        "./tests/frontends/python/parens.ast",
+    ] {
+        for algorithm in ["lr1", "lalr", "minimal"] {
+            let first = generate(source, algorithm, &build.path().join("first"))?;
+            let second = generate(source, algorithm, &build.path().join("second"))?;
+            assert!(
+                first == second,
+                "generating {source} with {algorithm} twice gave different output"
+            );
+        }
+    }
+
+    Ok(())
+}
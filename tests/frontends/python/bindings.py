@@ -0,0 +1,28 @@
+import parser
+from tokens import Token, TokenKind
+
+KINDS = {
+    "+": TokenKind.Plus,
+    "-": TokenKind.Minus,
+    "(": TokenKind.OpenParen,
+    ")": TokenKind.CloseParen,
+}
+
+
+def lex(s: str):
+    return [
+        Token(TokenKind.Int, ord(ch) - ord("0")) if ch.isdigit() else Token(KINDS[ch])
+        for ch in s
+    ]
+
+
+def case(input: str, expected: int):
+    result = parser.parse(lex(input))
+    assert result == expected, f"expected {result} = {expected}, {input:=}"
+
+
+if __name__ == "__main__":
+    case("7-2", 5)
+    case("1+2-4", -1)
+    case("-(3-5)", 2)
+    case("--1", 1)
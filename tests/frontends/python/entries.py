@@ -0,0 +1,26 @@
+import parser
+from tokens import Token, TokenKind
+
+
+def lex(s: str):
+    kinds = {"+": TokenKind.Plus, "/": TokenKind.Div}
+    return [
+        Token(TokenKind.Int, ord(ch) - ord("0")) if ch.isdigit() else Token(kinds[ch])
+        for ch in s
+    ]
+
+
+def fails(parse, input: str):
+    try:
+        result = parse(lex(input))
+    except Exception:
+        return
+    raise Exception(f"expected to fail but did not (returned {result} instead)")
+
+
+if __name__ == "__main__":
+    assert parser.parse_prog(lex("1+2/3/")) == [3, 3]
+    assert parser.parse(lex("4/")) == [4]
+    assert parser.parse_expr(lex("1+2+3")) == 6
+    fails(parser.parse_expr, "1+2/")
+    fails(parser.parse_prog, "1+2")
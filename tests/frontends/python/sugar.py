@@ -0,0 +1,28 @@
+import parser
+from tokens import Token, TokenKind
+
+KINDS = {
+    "+": TokenKind.Plus,
+    "-": TokenKind.Minus,
+    "*": TokenKind.Mul,
+    "/": TokenKind.Div,
+}
+
+
+def lex(s: str):
+    return [
+        Token(TokenKind.Int, ord(ch) - ord("0")) if ch.isdigit() else Token(KINDS[ch])
+        for ch in s
+    ]
+
+
+def case(input: str, expected: tuple):
+    result = parser.parse(lex(input))
+    assert result == expected, f"expected {result} = {expected}, {input:=}"
+
+
+if __name__ == "__main__":
+    case("", (None, [], [], None))
+    case("5", (None, [], [5], None))
+    case("-1*2*3+4+5/", (True, [(1, None), (2, None)], [3, 4, 5], True))
+    case("/", (None, [], [], True))
@@ -0,0 +1,90 @@
+// parser generated by asterisk
+mod parser;
+
+use parser::TokenKind;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    OpenParen,
+    CloseParen,
+    Comma,
+    Int(i32),
+    Ident(Name),
+    Plus,
+    Minus,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Name(String);
+
+fn call(name: &str, args: &[i32]) -> i32 {
+    match name {
+        "max" => args.iter().copied().max().unwrap_or(0),
+        "len" => args.len() as i32,
+        _ => panic!("unknown function {name}"),
+    }
+}
+
+fn lex(s: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = s.chars().peekable();
+    while let Some(ch) = chars.next() {
+        tokens.push(match ch {
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            ',' => Token::Comma,
+            '(' => Token::OpenParen,
+            ')' => Token::CloseParen,
+            '0'..='9' => Token::Int((ch as u8 - b'0') as i32),
+            'a'..='z' => {
+                let mut name = ch.to_string();
+                while let Some(&ch @ 'a'..='z') = chars.peek() {
+                    name.push(ch);
+                    chars.next();
+                }
+                Token::Ident(Name(name))
+            }
+            _ => panic!("unexpected char {ch}"),
+        });
+    }
+    tokens
+}
+
+fn case(input: &str, expected: i32) {
+    let toks = lex(input);
+    let result = parser::parse(toks.into_iter()).unwrap();
+    assert_eq!(result, expected, "generated by {input}");
+}
+
+fn main() {
+    case("1+2", 3);
+    case("5-2-1", 2);
+    case("(5-2)", 3);
+    case("max(1,7-1,2)", 6);
+    case("len()+len(1,2,3)", 3);
+
+    let error = parser::parse(lex("max(1").into_iter()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "unexpected end of input, expected one of `Plus`, `Minus`, `CloseParen` or `Comma`"
+    );
+    let parser::Error::UnexpectedToken { expected, .. } = error else {
+        panic!("expected an unexpected token");
+    };
+    assert_eq!(
+        expected,
+        [
+            Some(TokenKind::Plus),
+            Some(TokenKind::Minus),
+            Some(TokenKind::CloseParen),
+            Some(TokenKind::Comma)
+        ]
+    );
+
+    let error = parser::parse(lex("1+)").into_iter()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "unexpected `CloseParen`, expected one of `Ident`, `OpenParen` or `Int`"
+    );
+    assert_eq!(parser::kind(&Token::Int(1)), Some(TokenKind::Int));
+}
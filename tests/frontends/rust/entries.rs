@@ -0,0 +1,29 @@
+// parser generated by asterisk
+mod parser;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    Int(i32),
+    Plus,
+    Semi,
+}
+
+fn lex(s: &str) -> Vec<Token> {
+    s.chars()
+        .map(|ch| match ch {
+            '+' => Token::Plus,
+            ';' => Token::Semi,
+            '0'..='9' => Token::Int((ch as u8 - b'0') as i32),
+            _ => panic!("unexpected char {ch}"),
+        })
+        .collect()
+}
+
+fn main() {
+    let prog = parser::parse_prog(lex("1+2;3;").into_iter()).unwrap();
+    assert_eq!(prog, vec![3, 3]);
+    assert_eq!(parser::parse(lex("4;").into_iter()).unwrap(), vec![4]);
+    assert_eq!(parser::parse_expr(lex("1+2+3").into_iter()).unwrap(), 6);
+    assert!(parser::parse_expr(lex("1+2;").into_iter()).is_err());
+    assert!(parser::parse_prog(lex("1+2").into_iter()).is_err());
+}
@@ -0,0 +1,33 @@
+// parser generated by asterisk
+mod parser;
+
+use parser::TokenKind;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    Int(i32),
+    Plus,
+    Semi,
+}
+
+fn main() {
+    let tokens = vec![Token::Int(1), Token::Plus, Token::Int(2)];
+    assert_eq!(parser::parse(tokens.into_iter()).unwrap(), 3);
+
+    // tokens the grammar never uses are unexpected like any other
+    let tokens = vec![Token::Int(1), Token::Semi];
+    let error = parser::parse(tokens.into_iter()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "unexpected `Semi`, expected one of `Plus` or end of input"
+    );
+    let parser::Error::UnexpectedToken {
+        expected, received, ..
+    } = error
+    else {
+        panic!("expected an unexpected token");
+    };
+    assert_eq!(expected, [Some(TokenKind::Plus), None]);
+    assert_eq!(received, Some(Token::Semi));
+    assert_eq!(parser::kind(&Token::Semi), None);
+}
@@ -0,0 +1,69 @@
+// parser generated by asterisk
+mod parser;
+
+use std::ops::Range;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    OpenParen,
+    CloseParen,
+    Int(i32),
+    Ident(String),
+    Plus,
+}
+
+/// `s` annotated with where it was parsed from
+fn at(s: String, location: Range<usize>) -> String {
+    format!("{s}@{}..{}", location.start, location.end)
+}
+
+fn lex(s: &str) -> Vec<(Token, Range<usize>)> {
+    let mut tokens = Vec::new();
+    let mut chars = s.char_indices().peekable();
+    while let Some((start, ch)) = chars.next() {
+        let token = match ch {
+            ' ' => continue,
+            '+' => Token::Plus,
+            '(' => Token::OpenParen,
+            ')' => Token::CloseParen,
+            '0'..='9' => Token::Int((ch as u8 - b'0') as i32),
+            'a'..='z' => {
+                let mut name = ch.to_string();
+                while let Some(&(_, ch @ 'a'..='z')) = chars.peek() {
+                    name.push(ch);
+                    chars.next();
+                }
+                Token::Ident(name)
+            }
+            _ => panic!("unexpected char {ch}"),
+        };
+        let end = chars.peek().map_or(s.len(), |x| x.0);
+        tokens.push((token, start..end));
+    }
+    tokens
+}
+
+fn case(input: &str, expected: &str) {
+    let result = parser::parse(lex(input).into_iter()).unwrap();
+    assert_eq!(result, expected, "generated by {input}");
+}
+
+fn main() {
+    case("1", "1@0..1");
+    case("1 + 2", "1@0..1+2@4..5@0..5");
+    case("(1)", "(1@1..2)@0..3");
+    case("foo(1+2)", "foo@0..3(1@4..5+2@6..7@4..7)@0..8");
+    case("foo( )", "foo@0..3(@5..6)@0..6");
+
+    let error = parser::parse(lex("1 + )").into_iter()).unwrap_err();
+    let parser::Error::UnexpectedToken { location, .. } = error else {
+        panic!("expected an unexpected token");
+    };
+    assert_eq!(location, Some(4..5));
+
+    let error = parser::parse(lex("1 +").into_iter()).unwrap_err();
+    let parser::Error::UnexpectedToken { location, .. } = error else {
+        panic!("expected an unexpected token");
+    };
+    assert_eq!(location, None);
+}
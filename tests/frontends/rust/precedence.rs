@@ -0,0 +1,51 @@
+// parser generated by asterisk
+mod parser;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    OpenParen,
+    CloseParen,
+    Int(i32),
+    Plus,
+    Minus,
+    Mul,
+    Div,
+    Pow,
+    Eq,
+}
+
+fn lex(s: &str) -> Vec<Token> {
+    s.chars()
+        .map(|ch| match ch {
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Mul,
+            '/' => Token::Div,
+            '^' => Token::Pow,
+            '=' => Token::Eq,
+            '(' => Token::OpenParen,
+            ')' => Token::CloseParen,
+            '0'..='9' => Token::Int((ch as u8 - b'0') as i32),
+            _ => panic!("unexpected char {ch}"),
+        })
+        .collect()
+}
+
+fn case(input: &str, expected: i32) {
+    let toks = lex(input);
+    let result = parser::parse(toks.into_iter()).unwrap();
+    assert_eq!(result, expected, "generated by {input}");
+}
+
+fn main() {
+    case("1+2*3", 7);
+    case("1*2+3", 5);
+    case("8-3-2", 3);
+    case("8/4/2", 1);
+    case("2^3^2", 512);
+    case("-2^2", 4);
+    case("-2-3", -5);
+    case("(1+2)*3", 9);
+    case("1+2=3", 1);
+    assert!(parser::parse(lex("1=1=1").into_iter()).is_err());
+}
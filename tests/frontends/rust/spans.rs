@@ -0,0 +1,52 @@
+// parser generated by asterisk
+mod parser;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    Int(i32),
+    Plus,
+}
+
+/// a line and the columns on it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    line: usize,
+    columns: (usize, usize),
+}
+
+impl Span {
+    fn new(line: usize, start: usize, end: usize) -> Self {
+        Span {
+            line,
+            columns: (start, end),
+        }
+    }
+
+    fn merge(first: &Span, last: &Span) -> Span {
+        Span::new(first.line, first.columns.0, last.columns.1)
+    }
+}
+
+fn main() {
+    let tokens = vec![
+        (Token::Int(1), Span::new(3, 0, 1)),
+        (Token::Plus, Span::new(3, 2, 3)),
+        (Token::Int(20), Span::new(3, 4, 6)),
+    ];
+    assert_eq!(
+        parser::parse(tokens.into_iter()).unwrap(),
+        (21, Span::new(3, 0, 6))
+    );
+
+    let tokens = vec![(Token::Int(7), Span::new(1, 4, 5))];
+    assert_eq!(
+        parser::parse(tokens.into_iter()).unwrap(),
+        (7, Span::new(1, 4, 5))
+    );
+
+    let error = parser::parse(std::iter::empty()).unwrap_err();
+    let parser::Error::UnexpectedToken { location, .. } = error else {
+        panic!("expected an unexpected token");
+    };
+    assert_eq!(location, None);
+}
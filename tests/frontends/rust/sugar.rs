@@ -0,0 +1,48 @@
+// parser generated by asterisk
+mod parser;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    Int(i32),
+    Ident(String),
+    Plus,
+    Comma,
+    Semi,
+    Colon,
+    OpenParen,
+    CloseParen,
+}
+
+fn lex(s: &str) -> Vec<Token> {
+    s.chars()
+        .filter(|ch| !ch.is_whitespace())
+        .map(|ch| match ch {
+            '+' => Token::Plus,
+            ',' => Token::Comma,
+            ';' => Token::Semi,
+            ':' => Token::Colon,
+            '(' => Token::OpenParen,
+            ')' => Token::CloseParen,
+            '0'..='9' => Token::Int((ch as u8 - b'0') as i32),
+            'a'..='z' => Token::Ident(ch.to_string()),
+            _ => panic!("unexpected char {ch}"),
+        })
+        .collect()
+}
+
+fn case(input: &str, names: &[&str], numbers: &[i32]) {
+    let (n, x) = parser::parse(lex(input).into_iter()).unwrap();
+    assert_eq!(n, names, "generated by {input}");
+    assert_eq!(x, numbers, "generated by {input}");
+}
+
+fn main() {
+    case("", &[], &[]);
+    case(";", &[], &[]);
+    case("a b", &["a", "b"], &[]);
+    case("a 1, 2+3+4;", &["a"], &[1, 9]);
+    case("(2, 3), (), 1", &[], &[6, 1, 1]);
+    case(": a 1 b 2;", &["a", "b"], &[1, 2]);
+    assert!(parser::parse(lex("1,").into_iter()).is_err());
+    assert!(parser::parse(lex(":;").into_iter()).is_err());
+}
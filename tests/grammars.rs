@@ -0,0 +1,37 @@
+use common::{asterisk, build_dir};
+
+use crate::common::CommandExt;
+
+mod common;
+
+/// Generating a parser for `tests/grammars/large.ast` (a few hundred productions, several
+/// thousand LR(1) states) used to take minutes. It still takes a while in debug builds, so run it
+/// with `cargo test --release -- --ignored` when touching the table construction.
+#[test]
+#[ignore = "slow, run explicitly when changing the table construction"]
+fn large() -> anyhow::Result<()> {
+    let build = build_dir();
+    for (algorithm, expected) in [("lr1", 5766), ("lalr", 791), ("minimal", 791)] {
+        let output = build.path().join(format!("{algorithm}.rs"));
+        asterisk()
+            .arg("--algorithm")
+            .arg(algorithm)
+            .arg("--output")
+            .arg(&output)
+            .arg("./tests/grammars/large.ast")
+            .run()?;
+        // every state is a variant of the generated `State` enum, on a line of its own
+        let parser = std::fs::read_to_string(&output)?;
+        let states = parser
+            .lines()
+            .filter(|x| {
+                x.strip_prefix("State")
+                    .and_then(|x| x.strip_suffix(','))
+                    .is_some_and(|x| x.parse::<usize>().is_ok())
+            })
+            .count();
+        assert_eq!(states, expected, "{algorithm} built a different number of states");
+    }
+
+    Ok(())
+}
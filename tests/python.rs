@@ -6,21 +6,21 @@ use crate::common::CommandExt;
 
 mod common;
 
-#[test]
-fn parens() -> anyhow::Result<()> {
+/// generates a parser from `tests/frontends/python/{name}.ast` and runs
+/// `tests/frontends/python/{name}.py` next to it
+fn run_case(name: &str) -> anyhow::Result<()> {
     let build = build_dir();
     let file_path = build.path().join("parser.py");
 
-    asterisk_gen(file_path, "./tests/frontends/python/parens.ast").run()?;
+    asterisk_gen(file_path, format!("./tests/frontends/python/{name}.ast")).run()?;
     std::fs::copy(
-        "./tests/frontends/python/parens.py",
+        format!("./tests/frontends/python/{name}.py"),
         build.path().join("main.py"),
     )?;
     std::fs::copy(
         "./tests/frontends/python/tokens.py",
         build.path().join("tokens.py"),
     )?;
-    dbg!("hi");
 
     Command::new("python3")
         .arg("main.py")
@@ -29,3 +29,23 @@ fn parens() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn parens() -> anyhow::Result<()> {
+    run_case("parens")
+}
+
+#[test]
+fn bindings() -> anyhow::Result<()> {
+    run_case("bindings")
+}
+
+#[test]
+fn entries() -> anyhow::Result<()> {
+    run_case("entries")
+}
+
+#[test]
+fn sugar() -> anyhow::Result<()> {
+    run_case("sugar")
+}
@@ -6,30 +6,90 @@ use crate::common::CommandExt;
 
 mod common;
 
-#[test]
-fn parens() -> anyhow::Result<()> {
+/// generates a parser from `tests/frontends/rust/{name}.ast` with each backend and runs it with
+/// `tests/frontends/rust/{name}.rs` as the main file
+fn run_case(name: &str) -> anyhow::Result<()> {
     let build = build_dir();
     Command::new("cargo")
         .arg("init")
         .arg("--quiet")
         .arg("--name")
-        .arg("parens")
+        .arg(name)
         .current_dir(build.path())
         .run()?;
     let file_path = build.path().join("src").join("parser.rs");
-
-    asterisk_gen(file_path, "./tests/frontends/rust/parens.ast").run()?;
     std::fs::copy(
-        "./tests/frontends/rust/parens.rs",
+        format!("./tests/frontends/rust/{name}.rs"),
         build.path().join("src").join("main.rs"),
     )?;
 
-    Command::new("cargo")
-        .env("RUSTFLAGS", "-Awarnings")
-        .arg("run")
-        .arg("--quiet")
-        .current_dir(build.path())
-        .run()?;
+    for backend in ["match", "table"] {
+        let grammar = std::fs::read_to_string(format!("./tests/frontends/rust/{name}.ast"))?;
+        let grammar_path = build.path().join("grammar.ast");
+        std::fs::write(&grammar_path, format!("backend = {backend}\n{grammar}"))?;
+        asterisk_gen(&file_path, &grammar_path).run()?;
+
+        Command::new("cargo")
+            .env("RUSTFLAGS", "-Awarnings")
+            .arg("run")
+            .arg("--quiet")
+            .current_dir(build.path())
+            .run()?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn parens() -> anyhow::Result<()> {
+    run_case("parens")
+}
+
+#[test]
+fn precedence() -> anyhow::Result<()> {
+    run_case("precedence")
+}
+
+#[test]
+fn entries() -> anyhow::Result<()> {
+    run_case("entries")
+}
+
+#[test]
+fn sugar() -> anyhow::Result<()> {
+    run_case("sugar")
+}
 
+#[test]
+fn bindings() -> anyhow::Result<()> {
+    run_case("bindings")
+}
+
+#[test]
+fn locations() -> anyhow::Result<()> {
+    run_case("locations")
+}
+
+#[test]
+fn spans() -> anyhow::Result<()> {
+    run_case("spans")
+}
+
+#[test]
+fn foreign_tokens() -> anyhow::Result<()> {
+    run_case("foreign")
+}
+
+/// the code of a production is emitted once, no matter how many states reduce it
+#[test]
+fn actions_are_emitted_once() -> anyhow::Result<()> {
+    let build = build_dir();
+    let file_path = build.path().join("parser.rs");
+    asterisk_gen(&file_path, "./tests/frontends/rust/parens.ast").run()?;
+    let parser = std::fs::read_to_string(&file_path)?;
+    for code in ["v0 + v2", "v0 * 10 + v1", "v1"] {
+        let count = parser.matches(&format!("{{ {code} }}")).count();
+        assert_eq!(count, 1, "`{code}` is emitted {count} times");
+    }
     Ok(())
 }